@@ -0,0 +1,260 @@
+use std::{
+    collections::{BTreeSet, VecDeque},
+    path::PathBuf,
+};
+
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_common_assets::ron::RonAssetPlugin;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants::ACHIEVEMENT_TOAST_TIME,
+    sound::SoundEffect,
+    states::{GameStates, LetterCleared, LetterFailed, Statistics},
+    storage::{data_dir, read_ron, write_ron},
+    word_checks::{ActivateEffect, GuessKind, GuessOutcome, SubmittedWord},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins(RonAssetPlugin::<AchievementList>::new(&[
+        "achievements.ron",
+    ]))
+    .insert_resource(Achievements {
+        progress: AchievementProgress::load(),
+        ..default()
+    })
+    .init_resource::<AchievementToasts>()
+    .add_observer(submitted_word_observer)
+    .add_observer(guess_outcome_observer)
+    .add_observer(activate_effect_observer)
+    .add_observer(letter_cleared_observer)
+    .add_observer(letter_failed_observer)
+    .add_systems(OnEnter(GameStates::Printing), reset_letter_progress_system)
+    .add_systems(
+        Update,
+        (
+            unlock_achievements_system
+                .run_if(resource_changed::<Achievements>.or(resource_changed::<Statistics>)),
+            tick_achievement_toasts_system,
+        )
+            .chain()
+            .run_if(not(in_state(GameStates::Loading))),
+    );
+}
+
+#[derive(AssetCollection, Resource)]
+pub struct AchievementAssets {
+    #[asset(key = "achievements")]
+    pub list: Handle<AchievementList>,
+}
+
+#[derive(Debug, Deserialize, Asset, TypePath, Clone)]
+pub struct AchievementList(pub Vec<Achievement>);
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Achievement {
+    // Key the unlock is saved under, which should never change once released.
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub condition: AchievementCondition,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub enum AchievementCondition {
+    // Totals across every letter ever played.
+    LettersCleared(u32),
+    LettersFailed(u32),
+    // Letters cleared without guessing a single decoy.
+    FlawlessLetters(u32),
+    WordsSubmitted(u32),
+    // Counts within the letter being played.
+    CursesInLetter(u32),
+    EffectsInLetter(u32),
+    // Statistics reached during a run.
+    Money(i32),
+    Income(i32),
+    Score(i32),
+}
+
+impl AchievementCondition {
+    fn met(&self, achievements: &Achievements, stats: &Statistics) -> bool {
+        let AchievementProgress {
+            letters_cleared,
+            letters_failed,
+            flawless_letters,
+            words_submitted,
+            ..
+        } = achievements.progress;
+        let LetterProgress {
+            curses, effects, ..
+        } = achievements.letter;
+
+        match *self {
+            AchievementCondition::LettersCleared(amount) => letters_cleared >= amount,
+            AchievementCondition::LettersFailed(amount) => letters_failed >= amount,
+            AchievementCondition::FlawlessLetters(amount) => flawless_letters >= amount,
+            AchievementCondition::WordsSubmitted(amount) => words_submitted >= amount,
+            AchievementCondition::CursesInLetter(amount) => curses >= amount,
+            AchievementCondition::EffectsInLetter(amount) => effects >= amount,
+            AchievementCondition::Money(amount) => stats.money >= amount,
+            AchievementCondition::Income(amount) => stats.income >= amount,
+            AchievementCondition::Score(amount) => stats.score >= amount,
+        }
+    }
+}
+
+// Unlocks and the totals they depend on, kept in the data directory.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct AchievementProgress {
+    pub unlocked: BTreeSet<String>,
+    pub letters_cleared: u32,
+    pub letters_failed: u32,
+    pub flawless_letters: u32,
+    pub words_submitted: u32,
+}
+
+impl AchievementProgress {
+    pub fn path() -> Option<PathBuf> {
+        data_dir().map(|dir| dir.join("achievements.ron"))
+    }
+
+    pub fn load() -> Self {
+        Self::path()
+            .and_then(|path| read_ron(&path))
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        if let Some(path) = Self::path() {
+            write_ron(&path, self);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct LetterProgress {
+    decoy_guesses: u32,
+    curses: u32,
+    effects: u32,
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct Achievements {
+    pub progress: AchievementProgress,
+    letter: LetterProgress,
+}
+
+// Newly unlocked achievements, shown one at a time over the game.
+#[derive(Resource, Debug, Default)]
+pub struct AchievementToasts {
+    queue: VecDeque<Achievement>,
+    current: Option<Achievement>,
+    timer: Timer,
+}
+
+impl AchievementToasts {
+    pub fn current(&self) -> Option<&Achievement> {
+        self.current.as_ref()
+    }
+}
+
+fn reset_letter_progress_system(mut achievements: ResMut<Achievements>) {
+    achievements.letter = LetterProgress::default();
+}
+
+fn submitted_word_observer(
+    _trigger: Trigger<SubmittedWord>,
+    mut achievements: ResMut<Achievements>,
+) {
+    achievements.progress.words_submitted += 1;
+}
+
+fn guess_outcome_observer(trigger: Trigger<GuessOutcome>, mut achievements: ResMut<Achievements>) {
+    match trigger.event().kind {
+        GuessKind::Decoy => achievements.letter.decoy_guesses += 1,
+        GuessKind::Curse => achievements.letter.curses += 1,
+        _ => {}
+    }
+}
+
+fn activate_effect_observer(
+    _trigger: Trigger<ActivateEffect>,
+    mut achievements: ResMut<Achievements>,
+) {
+    achievements.letter.effects += 1;
+}
+
+fn letter_cleared_observer(
+    _trigger: Trigger<LetterCleared>,
+    mut achievements: ResMut<Achievements>,
+) {
+    achievements.progress.letters_cleared += 1;
+
+    if achievements.letter.decoy_guesses == 0 {
+        achievements.progress.flawless_letters += 1;
+    }
+
+    achievements.progress.save();
+}
+
+fn letter_failed_observer(_trigger: Trigger<LetterFailed>, mut achievements: ResMut<Achievements>) {
+    achievements.progress.letters_failed += 1;
+    achievements.progress.save();
+}
+
+fn unlock_achievements_system(
+    mut achievements: ResMut<Achievements>,
+    mut toasts: ResMut<AchievementToasts>,
+    achievement_assets: Res<AchievementAssets>,
+    achievement_lists: Res<Assets<AchievementList>>,
+    stats: Res<Statistics>,
+) {
+    let Some(AchievementList(list)) = achievement_lists.get(&achievement_assets.list) else {
+        return;
+    };
+
+    let newly_unlocked: Vec<_> = list
+        .iter()
+        .filter(|achievement| !achievements.progress.unlocked.contains(&achievement.id))
+        .filter(|achievement| achievement.condition.met(&achievements, &stats))
+        .cloned()
+        .collect();
+
+    if newly_unlocked.is_empty() {
+        return;
+    }
+
+    for achievement in newly_unlocked {
+        info!("unlocked achievement {}", achievement.name);
+        achievements
+            .progress
+            .unlocked
+            .insert(achievement.id.clone());
+        toasts.queue.push_back(achievement);
+    }
+
+    achievements.progress.save();
+}
+
+fn tick_achievement_toasts_system(
+    mut commands: Commands,
+    mut toasts: ResMut<AchievementToasts>,
+    time: Res<Time>,
+) {
+    toasts.timer.tick(time.delta());
+
+    if !toasts.timer.finished() {
+        return;
+    }
+
+    if let Some(achievement) = toasts.queue.pop_front() {
+        toasts.current = Some(achievement);
+        toasts.timer = Timer::from_seconds(ACHIEVEMENT_TOAST_TIME, TimerMode::Once);
+        commands.trigger(SoundEffect::Window);
+    } else if toasts.current.is_some() {
+        toasts.current = None;
+    }
+}
@@ -0,0 +1,323 @@
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    letters::{CurrentLetter, Effect, Testimonial},
+    states::{GameStates, LetterCleared, LetterFailed},
+    storage::{data_dir, read_ron, write_ron},
+    word_checks::{GuessKind, GuessOutcome},
+};
+
+// How many words to list as the hardest and easiest on the stats screen.
+const RANKED_WORDS: usize = 3;
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(Analytics::load())
+        .add_observer(guess_outcome_observer)
+        .add_observer(letter_cleared_observer)
+        .add_observer(letter_failed_observer)
+        .add_systems(OnEnter(GameStates::Playing), start_letter_record_system);
+}
+
+// Every letter ever played, kept in the data directory for tuning testimonials.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct AnalyticsLog {
+    pub letters: Vec<LetterRecord>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LetterRecord {
+    pub flavor_title: String,
+    // Seconds since the unix epoch when the letter was finished.
+    pub finished_at: u64,
+    pub cleared: bool,
+    // Time spent typing before the letter was cleared or ran out of time, in seconds.
+    pub seconds_taken: f32,
+    pub testimonials: Vec<TestimonialRecord>,
+    // Guesses that matched a decoy, or no word at all.
+    pub wrong_guesses: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TestimonialRecord {
+    pub kind: TestimonialKind,
+    // Message as shown, with its blank.
+    pub message: String,
+    pub target_word: String,
+    pub effect: Effect,
+    pub guessed: bool,
+    // Fell off screen on a difficulty where missed blessings do not respawn.
+    #[serde(default)]
+    pub lost: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestimonialKind {
+    Blessing,
+    Curse,
+}
+
+impl TestimonialRecord {
+    fn new(kind: TestimonialKind, testimonial: &Testimonial) -> Self {
+        Self {
+            kind,
+            message: testimonial.message.clone(),
+            target_word: testimonial.target_word.clone(),
+            effect: testimonial.effect.clone(),
+            guessed: testimonial.collected,
+            lost: testimonial.lost,
+        }
+    }
+}
+
+// Totals worked out from the log, refreshed whenever a letter is added to it.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsSummary {
+    pub letters_played: usize,
+    pub letters_cleared: usize,
+    pub average_seconds: f32,
+    // Blessing target words with how often they were guessed and shown, hardest first.
+    pub hardest_words: Vec<(String, usize, usize)>,
+    pub easiest_words: Vec<(String, usize, usize)>,
+}
+
+impl AnalyticsSummary {
+    fn new(log: &AnalyticsLog) -> Self {
+        let letters_played = log.letters.len();
+        let letters_cleared = log.letters.iter().filter(|letter| letter.cleared).count();
+        let average_seconds = if letters_played == 0 {
+            0.
+        } else {
+            log.letters
+                .iter()
+                .map(|letter| letter.seconds_taken)
+                .sum::<f32>()
+                / letters_played as f32
+        };
+
+        let mut words: HashMap<&str, (usize, usize)> = HashMap::new();
+        for testimonial in log
+            .letters
+            .iter()
+            .flat_map(|letter| &letter.testimonials)
+            .filter(|testimonial| testimonial.kind == TestimonialKind::Blessing)
+        {
+            let (guessed, shown) = words.entry(&testimonial.target_word).or_default();
+            *guessed += usize::from(testimonial.guessed);
+            *shown += 1;
+        }
+
+        let mut ranked: Vec<_> = words
+            .into_iter()
+            .map(|(word, (guessed, shown))| (word.to_string(), guessed, shown))
+            .collect();
+        ranked.sort_by(
+            |(a_word, a_guessed, a_shown), (b_word, b_guessed, b_shown)| {
+                let a_rate = *a_guessed as f32 / *a_shown as f32;
+                let b_rate = *b_guessed as f32 / *b_shown as f32;
+                a_rate
+                    .total_cmp(&b_rate)
+                    .then(b_shown.cmp(a_shown))
+                    .then(a_word.cmp(b_word))
+            },
+        );
+
+        let hardest_words = ranked.iter().take(RANKED_WORDS).cloned().collect();
+        let easiest_words = ranked.iter().rev().take(RANKED_WORDS).cloned().collect();
+
+        Self {
+            letters_played,
+            letters_cleared,
+            average_seconds,
+            hardest_words,
+            easiest_words,
+        }
+    }
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct Analytics {
+    log: AnalyticsLog,
+    pub summary: AnalyticsSummary,
+    // Wrong guesses and start time of the letter being played.
+    wrong_guesses: Vec<String>,
+    started_at: Duration,
+}
+
+impl Analytics {
+    pub fn path() -> Option<PathBuf> {
+        data_dir().map(|dir| dir.join("analytics.ron"))
+    }
+
+    pub fn load() -> Self {
+        let log: AnalyticsLog = Self::path()
+            .and_then(|path| read_ron(&path))
+            .unwrap_or_default();
+
+        Self {
+            summary: AnalyticsSummary::new(&log),
+            log,
+            ..default()
+        }
+    }
+
+    fn record(&mut self, letter: &CurrentLetter, cleared: bool, now: Duration) {
+        let finished_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        let testimonials = letter
+            .blessings
+            .iter()
+            .map(|blessing| TestimonialRecord::new(TestimonialKind::Blessing, blessing))
+            .chain(
+                letter
+                    .curses
+                    .iter()
+                    .map(|curse| TestimonialRecord::new(TestimonialKind::Curse, curse)),
+            )
+            .collect();
+
+        self.log.letters.push(LetterRecord {
+            flavor_title: letter.flavor.title.clone(),
+            finished_at,
+            cleared,
+            seconds_taken: now.saturating_sub(self.started_at).as_secs_f32(),
+            testimonials,
+            wrong_guesses: std::mem::take(&mut self.wrong_guesses),
+        });
+
+        self.summary = AnalyticsSummary::new(&self.log);
+
+        if let Some(path) = Self::path() {
+            write_ron(&path, &self.log);
+        }
+    }
+}
+
+fn start_letter_record_system(mut analytics: ResMut<Analytics>, time: Res<Time>) {
+    analytics.wrong_guesses.clear();
+    analytics.started_at = time.elapsed();
+}
+
+fn guess_outcome_observer(trigger: Trigger<GuessOutcome>, mut analytics: ResMut<Analytics>) {
+    let outcome = trigger.event();
+
+    if matches!(outcome.kind, GuessKind::Decoy | GuessKind::Miss) {
+        analytics.wrong_guesses.push(outcome.word.clone());
+    }
+}
+
+fn letter_cleared_observer(
+    _trigger: Trigger<LetterCleared>,
+    mut analytics: ResMut<Analytics>,
+    current_letter: Res<CurrentLetter>,
+    time: Res<Time>,
+) {
+    analytics.record(&current_letter, true, time.elapsed());
+}
+
+fn letter_failed_observer(
+    _trigger: Trigger<LetterFailed>,
+    mut analytics: ResMut<Analytics>,
+    current_letter: Res<CurrentLetter>,
+    time: Res<Time>,
+) {
+    analytics.record(&current_letter, false, time.elapsed());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blessing(target_word: &str, guessed: bool) -> TestimonialRecord {
+        TestimonialRecord {
+            kind: TestimonialKind::Blessing,
+            message: String::new(),
+            target_word: target_word.into(),
+            effect: Effect::Score(1),
+            guessed,
+            lost: false,
+        }
+    }
+
+    fn letter(
+        cleared: bool,
+        seconds_taken: f32,
+        testimonials: Vec<TestimonialRecord>,
+    ) -> LetterRecord {
+        LetterRecord {
+            flavor_title: String::new(),
+            finished_at: 0,
+            cleared,
+            seconds_taken,
+            testimonials,
+            wrong_guesses: vec![],
+        }
+    }
+
+    #[test]
+    fn empty_log_has_no_totals() {
+        let summary = AnalyticsSummary::new(&AnalyticsLog::default());
+
+        assert_eq!(summary.letters_played, 0);
+        assert_eq!(summary.average_seconds, 0.);
+        assert!(summary.hardest_words.is_empty());
+    }
+
+    #[test]
+    fn ranks_blessing_words_by_how_often_they_were_guessed() {
+        let curse = TestimonialRecord {
+            kind: TestimonialKind::Curse,
+            ..blessing("stamp", false)
+        };
+        let log = AnalyticsLog {
+            letters: vec![
+                letter(
+                    true,
+                    10.,
+                    vec![
+                        blessing("mail", true),
+                        blessing("post", false),
+                        blessing("ink", false),
+                        curse,
+                    ],
+                ),
+                letter(
+                    false,
+                    20.,
+                    vec![
+                        blessing("mail", true),
+                        blessing("post", true),
+                        blessing("ink", false),
+                        blessing("chain", false),
+                    ],
+                ),
+            ],
+        };
+
+        let summary = AnalyticsSummary::new(&log);
+        let words = |ranked: &[(String, usize, usize)]| {
+            ranked
+                .iter()
+                .map(|(word, _, _)| word.clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(summary.letters_played, 2);
+        assert_eq!(summary.letters_cleared, 1);
+        assert_eq!(summary.average_seconds, 15.);
+        // Words never guessed rank by how often they were shown.
+        assert_eq!(words(&summary.hardest_words), ["ink", "chain", "post"]);
+        assert_eq!(words(&summary.easiest_words), ["mail", "post", "chain"]);
+        assert_eq!(summary.easiest_words[0], ("mail".into(), 2, 2));
+    }
+}
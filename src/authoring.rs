@@ -0,0 +1,448 @@
+use std::path::PathBuf;
+
+use bevy::{asset::ron, prelude::*};
+use bevy_ratatui::{
+    RatatuiContext, RatatuiPlugins,
+    crossterm::event::{KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind},
+    event::{KeyEvent as RatatuiKeyEvent, MouseEvent as RatatuiMouseEvent},
+};
+use ratatui::{
+    layout::{Constraint, Layout, Position, Rect},
+    style::Stylize,
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Padding, Paragraph, StatefulWidget, Widget, Wrap},
+};
+
+use crate::{
+    constants::{
+        MAC_CYAN_COLOR, MAC_GREEN_COLOR, MAC_PURPLE_COLOR, MAC_RED_COLOR, MAC_YELLOW_COLOR,
+        PLASTIC_DARK_BACKGROUND_COLOR, PLASTIC_EMPHASIS_COLOR, PLASTIC_LIGHT_BACKGROUND_COLOR,
+        PLASTIC_MEDIUM_BACKGROUND_COLOR, PLASTIC_PRIMARY_COLOR, PLASTIC_SECONDARY_COLOR,
+    },
+    interface::widgets::letter::{LetterWidget, LetterWidgetState},
+    letters::{Effect, Flavor, Letter, Name, Pronouns, TestimonialStub},
+};
+
+// Sample senders cycled through in the preview, covering every set of pronouns.
+const SAMPLE_NAMES: [(&str, Pronouns); 3] = [
+    ("James", Pronouns::HeHimHis),
+    ("Mary", Pronouns::SheHerHers),
+    ("Robin", Pronouns::TheyThemTheir),
+];
+
+// Amount that up and down adjust the effect by.
+const EFFECT_STEP: i32 = 5;
+
+// Standalone app for writing testimonials, run through the `letter_author` binary.
+pub struct AuthoringPlugin;
+
+impl Plugin for AuthoringPlugin {
+    fn build(&self, app: &mut App) {
+        // The terminal plugin reads its render rate from the settings.
+        app.add_plugins((
+            crate::terminal::logging_plugin,
+            crate::settings::plugin,
+            crate::terminal::plugin,
+            RatatuiPlugins {
+                enable_mouse_capture: true,
+                ..default()
+            },
+        ))
+        .insert_resource(Draft::new(output_dir_from_args()))
+        .init_resource::<DraftLayout>()
+        .add_systems(
+            Update,
+            (
+                authoring_keyboard_system,
+                authoring_mouse_system,
+                authoring_draw_system,
+            )
+                .chain(),
+        );
+    }
+}
+
+// Letters directory to save into, i.e. `letter_author assets/letters`.
+fn output_dir_from_args() -> PathBuf {
+    std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("assets/letters"))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TestimonialKind {
+    Blessing,
+    Curse,
+    Decoy,
+}
+
+impl TestimonialKind {
+    fn next(self) -> Self {
+        match self {
+            TestimonialKind::Blessing => TestimonialKind::Curse,
+            TestimonialKind::Curse => TestimonialKind::Decoy,
+            TestimonialKind::Decoy => TestimonialKind::Blessing,
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            TestimonialKind::Blessing => "blessing",
+            TestimonialKind::Curse => "curse",
+            TestimonialKind::Decoy => "decoy",
+        }
+    }
+}
+
+#[derive(Resource, Debug)]
+struct Draft {
+    message: String,
+    target: usize,
+    effect: Effect,
+    kind: TestimonialKind,
+    sample: usize,
+    output_dir: PathBuf,
+    status: String,
+}
+
+impl Draft {
+    fn new(output_dir: PathBuf) -> Self {
+        Self {
+            message: String::new(),
+            target: 0,
+            effect: Effect::Money(10),
+            kind: TestimonialKind::Blessing,
+            sample: 0,
+            output_dir,
+            status: String::new(),
+        }
+    }
+
+    fn word_count(&self) -> usize {
+        self.message.split_whitespace().count()
+    }
+
+    fn clamp_target(&mut self) {
+        self.target = self.target.min(self.word_count().saturating_sub(1));
+    }
+
+    fn stub(&self) -> Option<TestimonialStub> {
+        if self.target >= self.word_count() {
+            return None;
+        }
+
+        Some(TestimonialStub {
+            message: self.message.trim().to_string(),
+            effect: self.effect.clone(),
+            targets: vec![self.target],
+        })
+    }
+
+    fn preview_letter(&self) -> Option<Letter> {
+        let (first_name, pronouns) = &SAMPLE_NAMES[self.sample];
+        let name = Name {
+            first_name: first_name.to_string(),
+            pronouns: pronouns.clone(),
+        };
+        let testimonial = self.stub()?.into_testimonial(name, 'Q');
+
+        let (blessings, curses) = match self.kind {
+            TestimonialKind::Blessing => (vec![testimonial], vec![]),
+            TestimonialKind::Curse => (vec![], vec![testimonial]),
+            TestimonialKind::Decoy => return None,
+        };
+
+        // Placeholders in the sample flavor go through the same interpolation as in game.
+        let flavor = Flavor {
+            title: "PREVIEW".into(),
+            body: "Forward this letter to {recipients} friend within {time_limit} seconds.".into(),
+            signoff: "Yours in {time_limit} seconds,".into(),
+            footer: String::new(),
+            theme: None,
+        };
+        let (recipients, time_limit) = (1, 60);
+
+        Some(Letter {
+            interpolated_flavor: flavor.interpolate(recipients, time_limit),
+            flavor,
+            recipients,
+            time_limit,
+            blessings,
+            curses,
+        })
+    }
+
+    // Writes the draft to the next free numbered file, i.e. `blessings/25.blessing.ron`.
+    fn save(&mut self) {
+        let Some(stub) = self.stub() else {
+            self.status = "nothing to save, message is empty".into();
+            return;
+        };
+
+        let extension = self.kind.extension();
+        let directory = self.output_dir.join(format!("{extension}s"));
+        let next_number = std::fs::read_dir(&directory)
+            .into_iter()
+            .flatten()
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .strip_suffix(&format!(".{extension}.ron"))
+                    .and_then(|number| number.parse::<usize>().ok())
+            })
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let path = directory.join(format!("{next_number}.{extension}.ron"));
+
+        let result = std::fs::create_dir_all(&directory)
+            .and_then(|_| std::fs::write(&path, stub_to_ron(&stub)));
+
+        self.status = match result {
+            Ok(()) => format!("saved {}", path.display()),
+            Err(error) => format!("could not save {}: {error}", path.display()),
+        };
+    }
+}
+
+// Formats a stub the same way as the hand-written files in `assets/letters`.
+fn stub_to_ron(stub: &TestimonialStub) -> String {
+    let message = ron::to_string(&stub.message).expect("strings should always serialize");
+    let effect = ron::to_string(&stub.effect).expect("effects should always serialize");
+    let targets = stub
+        .targets
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "TestimonialStub(\n    message: {message},\n    effect: {effect},\n    targets: [{targets}],\n)\n"
+    )
+}
+
+fn next_effect(effect: &Effect) -> Effect {
+    match effect {
+        Effect::Score(amount) => Effect::Money(*amount),
+        Effect::Money(amount) => Effect::Income(*amount),
+        Effect::Income(_) => Effect::Noop,
+        Effect::Noop => Effect::Score(EFFECT_STEP * 2),
+    }
+}
+
+fn adjust_effect(effect: &Effect, delta: i32) -> Effect {
+    match effect {
+        Effect::Score(amount) => Effect::Score(amount + delta),
+        Effect::Money(amount) => Effect::Money(amount + delta),
+        Effect::Income(amount) => Effect::Income(amount + delta),
+        Effect::Noop => Effect::Noop,
+    }
+}
+
+#[derive(Resource, Default)]
+struct DraftLayout {
+    word_areas: Vec<Rect>,
+}
+
+fn authoring_keyboard_system(
+    mut keyboard_input: EventReader<RatatuiKeyEvent>,
+    mut draft: ResMut<Draft>,
+    mut exit: EventWriter<AppExit>,
+) {
+    for event in keyboard_input.read() {
+        if event.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        let control = event.modifiers.contains(KeyModifiers::CONTROL);
+
+        match event.code {
+            KeyCode::Esc => {
+                exit.write(AppExit::Success);
+            }
+            KeyCode::Char('s') if control => draft.save(),
+            KeyCode::Char(c) if !control => {
+                draft.message.push(c);
+            }
+            KeyCode::Backspace => {
+                draft.message.pop();
+            }
+            KeyCode::Left => draft.target = draft.target.saturating_sub(1),
+            KeyCode::Right => draft.target += 1,
+            KeyCode::Up => draft.effect = adjust_effect(&draft.effect, EFFECT_STEP),
+            KeyCode::Down => draft.effect = adjust_effect(&draft.effect, -EFFECT_STEP),
+            KeyCode::Tab => draft.effect = next_effect(&draft.effect),
+            KeyCode::BackTab => draft.kind = draft.kind.next(),
+            KeyCode::F(2) => draft.sample = (draft.sample + 1) % SAMPLE_NAMES.len(),
+            _ => {}
+        }
+
+        draft.clamp_target();
+    }
+}
+
+fn authoring_mouse_system(
+    mut mouse_input: EventReader<RatatuiMouseEvent>,
+    mut draft: ResMut<Draft>,
+    layout: Res<DraftLayout>,
+) {
+    for event in mouse_input.read() {
+        if event.kind != MouseEventKind::Down(MouseButton::Left) {
+            continue;
+        }
+
+        let position = Position::new(event.column, event.row);
+        if let Some(index) = layout
+            .word_areas
+            .iter()
+            .position(|area| area.contains(position))
+        {
+            draft.target = index;
+        }
+    }
+}
+
+fn authoring_draw_system(
+    mut ratatui: ResMut<RatatuiContext>,
+    draft: Res<Draft>,
+    mut layout: ResMut<DraftLayout>,
+) -> Result {
+    ratatui.draw(|frame| {
+        let buf = frame.buffer_mut();
+        let area = buf.area;
+
+        Block::default()
+            .bg(PLASTIC_DARK_BACKGROUND_COLOR)
+            .render(area, buf);
+
+        let [main_area, help_area] =
+            *Layout::vertical([Constraint::Fill(1), Constraint::Length(2)]).split(area)
+        else {
+            unreachable!()
+        };
+
+        let [editor_area, preview_area] =
+            *Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)]).split(main_area)
+        else {
+            unreachable!()
+        };
+
+        let editor_block = Block::bordered()
+            .border_type(BorderType::Double)
+            .title(" TESTIMONIAL ")
+            .padding(Padding::proportional(1))
+            .bg(PLASTIC_LIGHT_BACKGROUND_COLOR)
+            .fg(PLASTIC_PRIMARY_COLOR);
+        let editor_inner_area = editor_block.inner(editor_area);
+        editor_block.render(editor_area, buf);
+
+        let [message_area, fields_area] =
+            *Layout::vertical([Constraint::Fill(1), Constraint::Length(6)])
+                .split(editor_inner_area)
+        else {
+            unreachable!()
+        };
+
+        // Words are laid out by hand so that their areas can be hit-tested for clicks.
+        layout.word_areas.clear();
+        let mut cursor = Position::new(message_area.x, message_area.y);
+        for (index, word) in draft.message.split_whitespace().enumerate() {
+            let width = word.chars().count() as u16;
+            if cursor.x > message_area.x && cursor.x + width > message_area.right() {
+                cursor = Position::new(message_area.x, cursor.y + 1);
+            }
+
+            let word_area = Rect::new(cursor.x, cursor.y, width, 1).intersection(message_area);
+            let span = if index == draft.target {
+                Span::from(word)
+                    .bold()
+                    .fg(PLASTIC_DARK_BACKGROUND_COLOR)
+                    .bg(MAC_YELLOW_COLOR)
+            } else {
+                Span::from(word).fg(PLASTIC_EMPHASIS_COLOR)
+            };
+            span.render(word_area, buf);
+
+            layout.word_areas.push(word_area);
+            cursor.x += width + 1;
+        }
+
+        let cursor_area = Rect::new(cursor.x, cursor.y, 1, 1).intersection(message_area);
+        Span::from("_")
+            .fg(PLASTIC_SECONDARY_COLOR)
+            .render(cursor_area, buf);
+
+        let (sample_name, _) = &SAMPLE_NAMES[draft.sample];
+        let fields = Text::from(vec![
+            Line::from(vec![
+                Span::from("kind:   ").fg(PLASTIC_SECONDARY_COLOR),
+                Span::from(draft.kind.extension()).fg(match draft.kind {
+                    TestimonialKind::Blessing => MAC_GREEN_COLOR,
+                    TestimonialKind::Curse => MAC_RED_COLOR,
+                    TestimonialKind::Decoy => MAC_PURPLE_COLOR,
+                }),
+            ]),
+            Line::from(vec![
+                Span::from("effect: ").fg(PLASTIC_SECONDARY_COLOR),
+                Span::from(format!("{:?}", draft.effect)),
+            ]),
+            Line::from(vec![
+                Span::from("target: ").fg(PLASTIC_SECONDARY_COLOR),
+                Span::from(draft.target.to_string()),
+            ]),
+            Line::from(vec![
+                Span::from("sample: ").fg(PLASTIC_SECONDARY_COLOR),
+                Span::from(sample_name.to_string()),
+            ]),
+            Line::from(""),
+            Line::from(draft.status.clone()).fg(MAC_CYAN_COLOR),
+        ]);
+        Paragraph::new(fields).render(fields_area, buf);
+
+        if let Some(letter) = draft.preview_letter() {
+            LetterWidget(&letter).render(
+                preview_area,
+                buf,
+                &mut LetterWidgetState::revealed(&letter),
+            );
+        } else {
+            let preview_block = Block::bordered()
+                .border_type(BorderType::Double)
+                .title(" PREVIEW ")
+                .padding(Padding::proportional(1))
+                .bg(PLASTIC_MEDIUM_BACKGROUND_COLOR)
+                .fg(PLASTIC_PRIMARY_COLOR);
+            let preview_text = match (draft.kind, draft.stub()) {
+                (TestimonialKind::Decoy, Some(stub)) => format!(
+                    "decoys only appear as falling words: {}",
+                    stub.message
+                        .split_whitespace()
+                        .nth(stub.targets[0])
+                        .unwrap_or_default()
+                ),
+                _ => "start typing a message to preview it".into(),
+            };
+
+            Paragraph::new(preview_text)
+                .wrap(Wrap { trim: true })
+                .block(preview_block)
+                .render(preview_area, buf);
+        }
+
+        Paragraph::new(Text::from(vec![
+            Line::from(
+                "type to edit  |  ←/→ or click: target  |  ↑/↓: amount  |  TAB: effect  |  \
+                SHIFT+TAB: kind",
+            ),
+            Line::from("F2: sample name  |  CTRL+S: save  |  ESC: quit"),
+        ]))
+        .centered()
+        .fg(PLASTIC_SECONDARY_COLOR)
+        .render(help_area, buf);
+    })?;
+
+    Ok(())
+}
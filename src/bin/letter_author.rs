@@ -0,0 +1,10 @@
+// Support configuring Bevy lints within code.
+#![cfg_attr(bevy_lint, feature(register_tool), register_tool(bevy))]
+
+use bevy::app::{App, AppExit};
+use chainmailer::AuthoringPlugin;
+
+// Usage: `cargo run --bin letter_author [letters directory]`.
+fn main() -> AppExit {
+    App::new().add_plugins(AuthoringPlugin).run()
+}
@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use ratatui::buffer::Buffer;
+
+use crate::storage::{data_dir, timestamp_file_stem};
+
+#[cfg(not(feature = "windowed"))]
+pub mod recording;
+pub mod screenshot;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<LastFrame>()
+        .init_resource::<FrameCapture>()
+        .add_observer(take_screenshot_observer)
+        .add_systems(PostUpdate, save_screenshot_system);
+
+    #[cfg(not(feature = "windowed"))]
+    app.add_plugins(recording::plugin);
+}
+
+// Copy of the most recently drawn terminal buffer, updated by `draw_system`. Its contents are only
+// copied while `FrameCapture` wants them, but its area always matches the terminal.
+#[derive(Resource, Deref, DerefMut, Default)]
+pub struct LastFrame(pub Buffer);
+
+// Captures waiting on drawn frames, so that frames are only copied when something saves them.
+#[derive(Resource, Default, Debug)]
+pub struct FrameCapture {
+    screenshot: bool,
+    recording: bool,
+    // Whether `LastFrame` holds this frame's contents.
+    pub copied: bool,
+}
+
+impl FrameCapture {
+    pub fn wanted(&self) -> bool {
+        self.screenshot || self.recording
+    }
+}
+
+#[derive(Event, Debug)]
+pub struct TakeScreenshot;
+
+fn take_screenshot_observer(_trigger: Trigger<TakeScreenshot>, mut capture: ResMut<FrameCapture>) {
+    capture.screenshot = true;
+}
+
+fn save_screenshot_system(mut capture: ResMut<FrameCapture>, last_frame: Res<LastFrame>) {
+    if !capture.screenshot || !capture.copied {
+        return;
+    }
+    capture.screenshot = false;
+
+    let Some(directory) = data_dir().map(|dir| dir.join("screenshots")) else {
+        warn!("screenshots are not supported on this platform");
+        return;
+    };
+
+    if let Err(error) = std::fs::create_dir_all(&directory) {
+        warn!("could not create {}: {error}", directory.display());
+        return;
+    }
+
+    let stem = timestamp_file_stem();
+
+    let files = [
+        ("ans", screenshot::buffer_to_ansi(&last_frame)),
+        ("html", screenshot::buffer_to_html(&last_frame)),
+        ("svg", screenshot::buffer_to_svg(&last_frame)),
+    ];
+
+    for (extension, contents) in files {
+        let path = directory.join(format!("{stem}.{extension}"));
+
+        match std::fs::write(&path, contents) {
+            Ok(()) => info!("saved screenshot to {}", path.display()),
+            Err(error) => warn!("could not save screenshot to {}: {error}", path.display()),
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bevy::prelude::*;
+use ratatui::{buffer::Buffer, style::Modifier};
+
+use crate::{cli::CliArgs, storage::data_dir};
+
+use super::{FrameCapture, LastFrame, screenshot::ansi_style};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<CastRecording>()
+        .add_observer(toggle_recording_observer)
+        .add_systems(Startup, start_recording_from_args_system)
+        .add_systems(PostUpdate, record_frame_system);
+}
+
+#[derive(Event, Debug)]
+pub struct ToggleRecording;
+
+// Asciicast v2 recording in progress, see <https://docs.asciinema.org/manual/asciicast/v2/>.
+#[derive(Resource, Default)]
+pub struct CastRecording(Option<ActiveRecording>);
+
+struct ActiveRecording {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    started: f64,
+    previous: Option<Buffer>,
+}
+
+impl CastRecording {
+    pub fn is_recording(&self) -> bool {
+        self.0.is_some()
+    }
+
+    fn start(&mut self, started: f64) {
+        let Some(directory) = data_dir().map(|dir| dir.join("recordings")) else {
+            warn!("recording is not supported on this platform");
+            return;
+        };
+
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        let path = directory.join(format!("{seconds}.cast"));
+
+        let file = std::fs::create_dir_all(&directory).and_then(|_| File::create(&path));
+        match file {
+            Ok(file) => {
+                info!("recording to {}", path.display());
+                self.0 = Some(ActiveRecording {
+                    path,
+                    writer: BufWriter::new(file),
+                    started,
+                    previous: None,
+                });
+            }
+            Err(error) => warn!("could not create {}: {error}", path.display()),
+        }
+    }
+
+    fn stop(&mut self) {
+        if let Some(mut recording) = self.0.take() {
+            match recording.writer.flush() {
+                Ok(()) => info!("saved recording to {}", recording.path.display()),
+                Err(error) => warn!("could not save {}: {error}", recording.path.display()),
+            }
+        }
+    }
+}
+
+fn start_recording_from_args_system(
+    cli_args: Res<CliArgs>,
+    mut recording: ResMut<CastRecording>,
+    mut capture: ResMut<FrameCapture>,
+    time: Res<Time<Real>>,
+) {
+    if cli_args.record_cast {
+        recording.start(time.elapsed_secs_f64());
+    }
+    capture.recording = recording.is_recording();
+}
+
+fn toggle_recording_observer(
+    _trigger: Trigger<ToggleRecording>,
+    mut recording: ResMut<CastRecording>,
+    mut capture: ResMut<FrameCapture>,
+    time: Res<Time<Real>>,
+) {
+    if recording.is_recording() {
+        recording.stop();
+    } else {
+        recording.start(time.elapsed_secs_f64());
+    }
+    capture.recording = recording.is_recording();
+}
+
+fn record_frame_system(
+    mut recording: ResMut<CastRecording>,
+    capture: Res<FrameCapture>,
+    last_frame: Res<LastFrame>,
+    time: Res<Time<Real>>,
+) {
+    let Some(active) = recording.0.as_mut() else {
+        return;
+    };
+
+    // A recording started after this frame was drawn has nothing to record until the next one.
+    if !capture.copied {
+        return;
+    }
+
+    let area = last_frame.area;
+    if area.is_empty() {
+        return;
+    }
+
+    let elapsed = time.elapsed_secs_f64() - active.started;
+    let mut events = vec![];
+
+    let output = match &active.previous {
+        Some(previous) if previous.area == area => diff_output(previous, &last_frame),
+        Some(_) => {
+            events.push((elapsed, "r", format!("{}x{}", area.width, area.height)));
+            full_output(&last_frame)
+        }
+        None => {
+            let header = format!(
+                "{{\"version\": 2, \"width\": {}, \"height\": {}, \"timestamp\": {}, \"env\": {{\"TERM\": \"xterm-256color\"}}}}\n",
+                area.width,
+                area.height,
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|duration| duration.as_secs())
+                    .unwrap_or_default(),
+            );
+            if let Err(error) = active.writer.write_all(header.as_bytes()) {
+                warn!("could not write recording header: {error}");
+            }
+            full_output(&last_frame)
+        }
+    };
+
+    if !output.is_empty() {
+        events.push((elapsed, "o", output));
+    }
+
+    for (elapsed, code, data) in events {
+        let line = format!("[{elapsed:.6}, \"{code}\", \"{}\"]\n", escape_json(&data));
+        if let Err(error) = active.writer.write_all(line.as_bytes()) {
+            warn!("could not write recording event: {error}");
+        }
+    }
+
+    active.previous = Some(last_frame.0.clone());
+}
+
+// Clears the screen and draws every cell of the buffer.
+fn full_output(buf: &Buffer) -> String {
+    let mut output = String::from("\x1b[2J");
+    output.push_str(&diff_output(&Buffer::empty(buf.area), buf));
+    output
+}
+
+// Escape sequences that turn the previous frame into the next one.
+fn diff_output(previous: &Buffer, next: &Buffer) -> String {
+    let mut output = String::new();
+    let mut last_position = None;
+    let mut last_style = None;
+
+    for (x, y, cell) in previous.diff(next) {
+        if last_position != Some((x.wrapping_sub(1), y)) {
+            output.push_str(&format!("\x1b[{};{}H", y + 1, x + 1));
+        }
+
+        let style: (_, _, Modifier) = (cell.fg, cell.bg, cell.modifier);
+        if last_style != Some(style) {
+            output.push_str(&ansi_style(cell.fg, cell.bg, cell.modifier));
+        }
+
+        output.push_str(cell.symbol());
+        last_position = Some((x, y));
+        last_style = Some(style);
+    }
+
+    output
+}
+
+fn escape_json(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for character in text.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_json_escapes_quotes_and_control_characters() {
+        assert_eq!(escape_json(r#"say "hi"\"#), r#"say \"hi\"\\"#);
+        assert_eq!(escape_json("a\nb\r"), r"a\nb\r");
+        assert_eq!(escape_json("\x1b[2J"), r"\u001b[2J");
+    }
+
+    #[test]
+    fn escape_json_keeps_other_characters() {
+        assert_eq!(escape_json("✉ café ~"), "✉ café ~");
+    }
+}
@@ -0,0 +1,309 @@
+use ratatui::{
+    buffer::Buffer,
+    style::{Color, Modifier},
+};
+
+// Colors used for cells that have their colors reset to the terminal default.
+const DEFAULT_FOREGROUND: (u8, u8, u8) = (229, 229, 229);
+const DEFAULT_BACKGROUND: (u8, u8, u8) = (0, 0, 0);
+
+// Dimensions of a single cell in SVG screenshots (in pixels).
+const SVG_CELL_WIDTH: f32 = 8.4;
+const SVG_CELL_HEIGHT: f32 = 17.0;
+const SVG_FONT_SIZE: f32 = 14.0;
+
+// SGR codes for each text modifier.
+const MODIFIER_CODES: [(Modifier, u8); 9] = [
+    (Modifier::BOLD, 1),
+    (Modifier::DIM, 2),
+    (Modifier::ITALIC, 3),
+    (Modifier::UNDERLINED, 4),
+    (Modifier::SLOW_BLINK, 5),
+    (Modifier::RAPID_BLINK, 6),
+    (Modifier::REVERSED, 7),
+    (Modifier::HIDDEN, 8),
+    (Modifier::CROSSED_OUT, 9),
+];
+
+// Consecutive cells within a row that share the same style.
+struct Run {
+    x: u16,
+    fg: Color,
+    bg: Color,
+    modifier: Modifier,
+    text: String,
+    width: u16,
+}
+
+fn row_runs(buf: &Buffer, y: u16) -> Vec<Run> {
+    let mut runs: Vec<Run> = vec![];
+
+    for x in buf.area.left()..buf.area.right() {
+        let cell = &buf[(x, y)];
+
+        match runs.last_mut() {
+            Some(run)
+                if run.fg == cell.fg && run.bg == cell.bg && run.modifier == cell.modifier =>
+            {
+                run.text.push_str(cell.symbol());
+                run.width += 1;
+            }
+            _ => runs.push(Run {
+                x,
+                fg: cell.fg,
+                bg: cell.bg,
+                modifier: cell.modifier,
+                text: cell.symbol().to_string(),
+                width: 1,
+            }),
+        }
+    }
+
+    runs
+}
+
+// Escape sequence that resets the terminal style, then applies the given one.
+pub fn ansi_style(fg: Color, bg: Color, modifier: Modifier) -> String {
+    let mut codes = vec!["0".to_string()];
+
+    for (flag, code) in MODIFIER_CODES {
+        if modifier.contains(flag) {
+            codes.push(code.to_string());
+        }
+    }
+
+    codes.push(ansi_color(fg, 30));
+    codes.push(ansi_color(bg, 40));
+
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+// SGR parameters for a color, where `base` is 30 for foreground and 40 for background.
+fn ansi_color(color: Color, base: u8) -> String {
+    match color {
+        Color::Reset => format!("{}", base + 9),
+        Color::Black => format!("{}", base),
+        Color::Red => format!("{}", base + 1),
+        Color::Green => format!("{}", base + 2),
+        Color::Yellow => format!("{}", base + 3),
+        Color::Blue => format!("{}", base + 4),
+        Color::Magenta => format!("{}", base + 5),
+        Color::Cyan => format!("{}", base + 6),
+        Color::Gray => format!("{}", base + 7),
+        Color::DarkGray => format!("{}", base + 60),
+        Color::LightRed => format!("{}", base + 61),
+        Color::LightGreen => format!("{}", base + 62),
+        Color::LightYellow => format!("{}", base + 63),
+        Color::LightBlue => format!("{}", base + 64),
+        Color::LightMagenta => format!("{}", base + 65),
+        Color::LightCyan => format!("{}", base + 66),
+        Color::White => format!("{}", base + 67),
+        Color::Indexed(index) => format!("{};5;{index}", base + 8),
+        Color::Rgb(r, g, b) => format!("{};2;{r};{g};{b}", base + 8),
+    }
+}
+
+// Approximates how a terminal using the xterm palette would display a color.
+fn color_to_rgb(color: Color, default: (u8, u8, u8)) -> (u8, u8, u8) {
+    const PALETTE: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (205, 0, 0),
+        (0, 205, 0),
+        (205, 205, 0),
+        (0, 0, 238),
+        (205, 0, 205),
+        (0, 205, 205),
+        (229, 229, 229),
+        (127, 127, 127),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (92, 92, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+
+    match color {
+        Color::Reset => default,
+        Color::Black => PALETTE[0],
+        Color::Red => PALETTE[1],
+        Color::Green => PALETTE[2],
+        Color::Yellow => PALETTE[3],
+        Color::Blue => PALETTE[4],
+        Color::Magenta => PALETTE[5],
+        Color::Cyan => PALETTE[6],
+        Color::Gray => PALETTE[7],
+        Color::DarkGray => PALETTE[8],
+        Color::LightRed => PALETTE[9],
+        Color::LightGreen => PALETTE[10],
+        Color::LightYellow => PALETTE[11],
+        Color::LightBlue => PALETTE[12],
+        Color::LightMagenta => PALETTE[13],
+        Color::LightCyan => PALETTE[14],
+        Color::White => PALETTE[15],
+        Color::Indexed(index @ 0..=15) => PALETTE[index as usize],
+        Color::Indexed(index @ 16..=231) => {
+            let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+            let index = index - 16;
+            (level(index / 36), level(index / 6 % 6), level(index % 6))
+        }
+        Color::Indexed(index) => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+        Color::Rgb(r, g, b) => (r, g, b),
+    }
+}
+
+fn hex(color: Color, default: (u8, u8, u8)) -> String {
+    let (r, g, b) = color_to_rgb(color, default);
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+// Resolved (foreground, background) hex colors of a run, with reversed and hidden applied.
+fn run_colors(run: &Run) -> (String, String) {
+    let mut fg = hex(run.fg, DEFAULT_FOREGROUND);
+    let mut bg = hex(run.bg, DEFAULT_BACKGROUND);
+
+    if run.modifier.contains(Modifier::REVERSED) {
+        std::mem::swap(&mut fg, &mut bg);
+    }
+    if run.modifier.contains(Modifier::HIDDEN) {
+        fg.clone_from(&bg);
+    }
+
+    (fg, bg)
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub fn buffer_to_ansi(buf: &Buffer) -> String {
+    let mut output = String::new();
+
+    for y in buf.area.top()..buf.area.bottom() {
+        for run in row_runs(buf, y) {
+            output.push_str(&ansi_style(run.fg, run.bg, run.modifier));
+            output.push_str(&run.text);
+        }
+        output.push_str("\x1b[0m\n");
+    }
+
+    output
+}
+
+pub fn buffer_to_html(buf: &Buffer) -> String {
+    let mut rows = vec![];
+
+    for y in buf.area.top()..buf.area.bottom() {
+        let mut row = String::new();
+
+        for run in row_runs(buf, y) {
+            let (fg, bg) = run_colors(&run);
+            let mut style = format!("color:{fg};background-color:{bg};");
+
+            if run.modifier.contains(Modifier::BOLD) {
+                style.push_str("font-weight:bold;");
+            }
+            if run.modifier.contains(Modifier::DIM) {
+                style.push_str("opacity:0.6;");
+            }
+            if run.modifier.contains(Modifier::ITALIC) {
+                style.push_str("font-style:italic;");
+            }
+            if run.modifier.contains(Modifier::UNDERLINED) {
+                style.push_str("text-decoration:underline;");
+            }
+            if run.modifier.contains(Modifier::CROSSED_OUT) {
+                style.push_str("text-decoration:line-through;");
+            }
+
+            row.push_str(&format!(
+                "<span style=\"{style}\">{}</span>",
+                escape_xml(&run.text)
+            ));
+        }
+
+        rows.push(row);
+    }
+
+    format!(
+        "<!DOCTYPE html>\n\
+        <html>\n\
+        <head>\n\
+        <meta charset=\"utf-8\">\n\
+        <title>CHAINMAILER</title>\n\
+        <style>\n\
+        body {{ margin: 0; background-color: {}; }}\n\
+        pre {{ margin: 0; font-family: monospace; line-height: 1.2; }}\n\
+        </style>\n\
+        </head>\n\
+        <body>\n\
+        <pre>{}</pre>\n\
+        </body>\n\
+        </html>\n",
+        hex(Color::Reset, DEFAULT_BACKGROUND),
+        rows.join("\n")
+    )
+}
+
+pub fn buffer_to_svg(buf: &Buffer) -> String {
+    let width = buf.area.width as f32 * SVG_CELL_WIDTH;
+    let height = buf.area.height as f32 * SVG_CELL_HEIGHT;
+    let mut elements = vec![];
+
+    for y in buf.area.top()..buf.area.bottom() {
+        let top = (y - buf.area.y) as f32 * SVG_CELL_HEIGHT;
+
+        for run in row_runs(buf, y) {
+            let (fg, bg) = run_colors(&run);
+            let left = (run.x - buf.area.x) as f32 * SVG_CELL_WIDTH;
+            let run_width = run.width as f32 * SVG_CELL_WIDTH;
+
+            elements.push(format!(
+                "<rect x=\"{left}\" y=\"{top}\" width=\"{run_width}\" height=\"{SVG_CELL_HEIGHT}\" fill=\"{bg}\"/>"
+            ));
+
+            if run.text.trim().is_empty() {
+                continue;
+            }
+
+            let mut attributes = format!("fill=\"{fg}\"");
+            if run.modifier.contains(Modifier::BOLD) {
+                attributes.push_str(" font-weight=\"bold\"");
+            }
+            if run.modifier.contains(Modifier::DIM) {
+                attributes.push_str(" opacity=\"0.6\"");
+            }
+            if run.modifier.contains(Modifier::ITALIC) {
+                attributes.push_str(" font-style=\"italic\"");
+            }
+            if run.modifier.contains(Modifier::UNDERLINED) {
+                attributes.push_str(" text-decoration=\"underline\"");
+            }
+            if run.modifier.contains(Modifier::CROSSED_OUT) {
+                attributes.push_str(" text-decoration=\"line-through\"");
+            }
+
+            // Text is stretched to exactly fill its cells, so that runs line up on the grid.
+            elements.push(format!(
+                "<text x=\"{left}\" y=\"{}\" textLength=\"{run_width}\" lengthAdjust=\"spacingAndGlyphs\" {attributes}>{}</text>",
+                top + SVG_FONT_SIZE,
+                escape_xml(&run.text)
+            ));
+        }
+    }
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n\
+        <style>text {{ font-family: monospace; font-size: {SVG_FONT_SIZE}px; white-space: pre; }}</style>\n\
+        {}\n\
+        </svg>\n",
+        elements.join("\n")
+    )
+}
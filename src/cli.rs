@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(CliArgs::parse());
+}
+
+// Flags passed on the command line, i.e. `chainmailer --seed 42 --record-replay`.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct CliArgs {
+    // Start recording an asciicast as soon as the game launches.
+    pub record_cast: bool,
+    // Seed for every random number generator in the game.
+    pub seed: Option<u64>,
+    // Log every game action to a replay file.
+    pub record_replay: bool,
+    // Replay file to feed game actions from, instead of live input.
+    pub replay: Option<PathBuf>,
+}
+
+impl CliArgs {
+    pub fn parse() -> Self {
+        let mut cli_args = Self::default();
+        let mut args = std::env::args().skip(1);
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--record-cast" => cli_args.record_cast = true,
+                "--record-replay" => cli_args.record_replay = true,
+                "--seed" => match args.next().and_then(|seed| seed.parse().ok()) {
+                    Some(seed) => cli_args.seed = Some(seed),
+                    None => warn!("--seed expects a number"),
+                },
+                "--replay" => match args.next() {
+                    Some(path) => cli_args.replay = Some(PathBuf::from(path)),
+                    None => warn!("--replay expects a file"),
+                },
+                _ => warn!("ignoring unknown argument {arg}"),
+            }
+        }
+
+        cli_args
+    }
+}
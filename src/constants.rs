@@ -32,6 +32,31 @@ pub const LETTER_PADDING: u16 = 1;
 // Length of every side of each word cube.
 pub const WORD_CUBE_LENGTH: f32 = 0.7;
 
+// Side of each block that word meshes are built from.
+pub const WORD_VOXEL_SIZE: f32 = 0.08;
+
+// Smallest scene (in cells) that word meshes are drawn in, below which words fall back to labels.
+pub const WORD_MESH_MIN_SCENE_WIDTH: u16 = 120;
+pub const WORD_MESH_MIN_SCENE_HEIGHT: u16 = 40;
+
+// Speed (in units per second) word cubes fall at, before their motion pattern is applied.
+pub const WORD_CUBE_FALL_SPEED: f32 = 0.45;
+
+// Number of motion patterns picked for each letter.
+pub const LETTER_MOTION_PATTERNS: usize = 2;
+
+// Time (in milliseconds) between chances for an obstacle or power-up to spawn.
+pub const OBJECT_SPAWN_INTERVAL: u64 = 3000;
+
+// Number of word cubes spawned together in a swarm formation.
+pub const SWARM_SIZE: usize = 3;
+
+// How far down the screen (as a fraction of the way) word labels start ramping towards red.
+pub const URGENCY_RAMP_START: f32 = 0.6;
+
+// How far down the screen (as a fraction of the way) word labels start blinking.
+pub const URGENCY_BLINK_START: f32 = 0.85;
+
 // Range of possible time limits (in seconds) per letter.
 pub const TIME_LIMIT_RANGE: RangeInclusive<usize> = 40..=60;
 
@@ -44,8 +69,23 @@ pub const CURSE_RANGE: RangeInclusive<usize> = 3..=5;
 // Range of possible decoy quantites per letter.
 pub const DECOY_RANGE: RangeInclusive<usize> = 8..=12;
 
-// Amount of confetti to spawn for each guessed word.
-pub const CONFETTI_AMOUNT: usize = 32;
+// Minimum time (in seconds) the typing tally stays up between letters, unless skipped.
+pub const RESET_TALLY_TIME: f32 = 4.0;
+
+// Time (in seconds) each achievement toast stays on screen.
+pub const ACHIEVEMENT_TOAST_TIME: f32 = 4.0;
+
+// Characters per second the letter text fill scrolls by, before speeding up as time runs out.
+pub const LETTER_FILL_SPEED: f32 = 14.0;
+
+// Time (in seconds) the screen glitches for after guessing a curse.
+pub const GLITCH_TIME: f32 = 0.35;
+
+// Characters swapped into the screen while it glitches.
+pub const GLITCH_GLYPHS: [char; 12] = ['#', '%', '&', '$', '?', '!', '/', '\\', '|', '<', '>', '~'];
+
+// Cells per world unit for confetti emitted over the letter.
+pub const LETTER_CONFETTI_SCALE: f32 = 12.0;
 
 // Custom border using half-cell borders on the top and bottom, and double borders on the sides.
 pub const CUSTOM_BORDERS: border::Set = border::Set {
@@ -0,0 +1,303 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    letters::{CurrentLetter, Letter, Testimonial},
+    settings::Settings,
+    states::LetterCleared,
+    storage::{data_dir, timestamp_file_stem},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(store_cleared_letter_observer)
+        .add_observer(export_letter_observer);
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    Text,
+    Markdown,
+    #[default]
+    Eml,
+}
+
+impl ExportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Text => "txt",
+            ExportFormat::Markdown => "md",
+            ExportFormat::Eml => "eml",
+        }
+    }
+}
+
+// Requests that the most recently cleared letter be written to the data directory.
+#[derive(Event, Debug)]
+pub struct ExportLetter;
+
+// Copy of the last letter that was cleared, kept around after the game moves on to the next.
+#[derive(Resource, Deref, Debug)]
+pub struct LastClearedLetter(pub Letter);
+
+fn store_cleared_letter_observer(
+    _trigger: Trigger<LetterCleared>,
+    mut commands: Commands,
+    current_letter: Res<CurrentLetter>,
+) {
+    commands.insert_resource(LastClearedLetter(current_letter.0.clone()));
+}
+
+fn export_letter_observer(
+    _trigger: Trigger<ExportLetter>,
+    last_cleared_letter: Option<Res<LastClearedLetter>>,
+    settings: Res<Settings>,
+) {
+    let Some(letter) = last_cleared_letter else {
+        info!("no cleared letter to export yet");
+        return;
+    };
+
+    let Some(directory) = data_dir().map(|dir| dir.join("letters")) else {
+        warn!("exporting letters is not supported on this platform");
+        return;
+    };
+
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    let format = settings.export_format;
+    let contents = match format {
+        ExportFormat::Text => letter_to_text(&letter),
+        ExportFormat::Markdown => letter_to_markdown(&letter),
+        ExportFormat::Eml => letter_to_eml(&letter, seconds),
+    };
+
+    let path = directory.join(format!(
+        "{}-{}.{}",
+        timestamp_file_stem(),
+        slugify(&letter.flavor.title),
+        format.extension()
+    ));
+
+    let result = std::fs::create_dir_all(&directory).and_then(|_| std::fs::write(&path, contents));
+
+    match result {
+        Ok(()) => info!("exported letter to {}", path.display()),
+        Err(error) => warn!("could not export letter to {}: {error}", path.display()),
+    }
+}
+
+fn testimonial_line(testimonial: &Testimonial) -> String {
+    if testimonial.collected {
+        format!(
+            "{} ({})",
+            testimonial.revealed_message(),
+            testimonial.effect
+        )
+    } else {
+        testimonial.message.clone()
+    }
+}
+
+pub fn letter_to_text(letter: &Letter) -> String {
+    let mut lines = vec![
+        letter.flavor.title.clone(),
+        String::new(),
+        letter.interpolated_flavor.body.clone(),
+        String::new(),
+        "THOSE WHO CONTINUED THE CHAIN:".into(),
+    ];
+    lines.extend(
+        letter
+            .blessings
+            .iter()
+            .map(|blessing| format!("+ {}", testimonial_line(blessing))),
+    );
+    lines.push(String::new());
+    lines.push("THOSE WHO BROKE THE CHAIN:".into());
+    lines.extend(
+        letter
+            .curses
+            .iter()
+            .map(|curse| format!("- {}", testimonial_line(curse))),
+    );
+    lines.extend([
+        String::new(),
+        letter.interpolated_flavor.signoff.clone(),
+        String::new(),
+        letter.flavor.footer.clone(),
+    ]);
+
+    lines.join("\n") + "\n"
+}
+
+pub fn letter_to_markdown(letter: &Letter) -> String {
+    let mut lines = vec![
+        format!("# {}", letter.flavor.title),
+        String::new(),
+        letter.interpolated_flavor.body.clone(),
+        String::new(),
+        "## Those who continued the chain".into(),
+        String::new(),
+    ];
+    lines.extend(
+        letter
+            .blessings
+            .iter()
+            .map(|blessing| format!("- {}", escape_markdown(&testimonial_line(blessing)))),
+    );
+    lines.extend([
+        String::new(),
+        "## Those who broke the chain".into(),
+        String::new(),
+    ]);
+    lines.extend(
+        letter
+            .curses
+            .iter()
+            .map(|curse| format!("- {}", escape_markdown(&testimonial_line(curse)))),
+    );
+    lines.extend([
+        String::new(),
+        letter.interpolated_flavor.signoff.clone(),
+        String::new(),
+        format!("_{}_", letter.flavor.footer),
+    ]);
+
+    lines.join("\n") + "\n"
+}
+
+// RFC 5322 message with the plain text letter as its body.
+pub fn letter_to_eml(letter: &Letter, seconds: u64) -> String {
+    let headers = [
+        "From: chainmailer <chainmailer@localhost>".to_string(),
+        "To: undisclosed-recipients:;".into(),
+        format!("Subject: {}", encode_header(&letter.flavor.title)),
+        format!("Date: {}", rfc5322_date(seconds)),
+        format!(
+            "Message-ID: <{seconds}.{}@chainmailer>",
+            slugify(&letter.flavor.title)
+        ),
+        "MIME-Version: 1.0".into(),
+        "Content-Type: text/plain; charset=utf-8".into(),
+        "Content-Transfer-Encoding: 8bit".into(),
+    ];
+
+    let body = letter_to_text(letter);
+
+    // Lines in an email are always terminated with CRLF.
+    format!("{}\n\n{}", headers.join("\n"), body).replace('\n', "\r\n")
+}
+
+fn escape_markdown(text: &str) -> String {
+    // Blanks are runs of underscores, which markdown would otherwise read as emphasis.
+    text.replace('_', "\\_")
+}
+
+// Non-ASCII headers must be sent as RFC 2047 encoded words.
+fn encode_header(text: &str) -> String {
+    if text.is_ascii() {
+        return text.to_string();
+    }
+
+    let encoded: String = text
+        .bytes()
+        .map(|byte| match byte {
+            b' ' => "_".to_string(),
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' => (byte as char).to_string(),
+            _ => format!("={byte:02X}"),
+        })
+        .collect();
+
+    format!("=?utf-8?Q?{encoded}?=")
+}
+
+fn rfc5322_date(seconds: u64) -> String {
+    const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let days = seconds / 86400;
+    let time_of_day = seconds % 86400;
+    let (year, month, day) = civil_from_days(days as i64);
+
+    format!(
+        "{}, {day} {} {year} {:02}:{:02}:{:02} +0000",
+        WEEKDAYS[(days % 7) as usize],
+        MONTHS[month as usize - 1],
+        time_of_day / 3600,
+        time_of_day % 3600 / 60,
+        time_of_day % 60,
+    )
+}
+
+// Converts days since the unix epoch into a (year, month, day) date.
+// See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+fn slugify(text: &str) -> String {
+    text.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_from_days_converts_known_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(11016), (2000, 2, 29));
+        assert_eq!(civil_from_days(11017), (2000, 3, 1));
+        assert_eq!(civil_from_days(19782), (2024, 2, 29));
+    }
+
+    #[test]
+    fn rfc5322_date_formats_weekday_and_time() {
+        assert_eq!(rfc5322_date(0), "Thu, 1 Jan 1970 00:00:00 +0000");
+        assert_eq!(
+            rfc5322_date(1_709_210_096),
+            "Thu, 29 Feb 2024 12:34:56 +0000"
+        );
+    }
+
+    #[test]
+    fn encode_header_only_encodes_non_ascii() {
+        assert_eq!(encode_header("Chain letter"), "Chain letter");
+        assert_eq!(
+            encode_header("Café au lait"),
+            "=?utf-8?Q?Caf=C3=A9_au_lait?="
+        );
+    }
+
+    #[test]
+    fn slugify_joins_words_with_dashes() {
+        assert_eq!(slugify("The Lucky Letter!"), "the-lucky-letter");
+        assert_eq!(slugify("  --  "), "");
+    }
+}
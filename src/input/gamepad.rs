@@ -0,0 +1,187 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{replay::live_input, states::GameStates};
+
+use super::{GameAction, TranslateInput};
+
+// Letters offered by the on-screen picker, laid out in rows of `LETTER_PICKER_COLUMNS`.
+pub const LETTER_PICKER_LETTERS: [char; 26] = [
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's',
+    't', 'u', 'v', 'w', 'x', 'y', 'z',
+];
+pub const LETTER_PICKER_COLUMNS: usize = 7;
+
+// How far the stick must be pushed to move the selection, and how quickly it repeats when held.
+const STICK_THRESHOLD: f32 = 0.5;
+const STICK_REPEAT_DELAY: Duration = Duration::from_millis(350);
+const STICK_REPEAT_RATE: Duration = Duration::from_millis(120);
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<LetterPicker>().add_systems(
+        Update,
+        (
+            show_letter_picker_system,
+            (move_letter_picker_system, translate_gamepad_system)
+                .chain()
+                .in_set(TranslateInput)
+                .run_if(live_input),
+        ),
+    );
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct LetterPicker {
+    // Only shown while a gamepad is connected.
+    pub visible: bool,
+    pub selected: usize,
+    held_direction: Option<IVec2>,
+    repeat_timer: Timer,
+}
+
+impl LetterPicker {
+    pub fn selected_letter(&self) -> char {
+        LETTER_PICKER_LETTERS[self.selected]
+    }
+
+    fn step(&mut self, direction: IVec2) {
+        let rows = LETTER_PICKER_LETTERS.len().div_ceil(LETTER_PICKER_COLUMNS) as i32;
+        let columns = LETTER_PICKER_COLUMNS as i32;
+
+        let mut column = self.selected as i32 % columns;
+        let mut row = self.selected as i32 / columns;
+
+        // Wraps around both axes, skipping the empty cells at the end of the last row.
+        loop {
+            column = (column + direction.x).rem_euclid(columns);
+            row = (row - direction.y).rem_euclid(rows);
+
+            let index = (row * columns + column) as usize;
+            if index < LETTER_PICKER_LETTERS.len() {
+                self.selected = index;
+                return;
+            }
+        }
+    }
+}
+
+fn show_letter_picker_system(mut letter_picker: ResMut<LetterPicker>, gamepads: Query<&Gamepad>) {
+    let visible = !gamepads.is_empty();
+    if letter_picker.visible != visible {
+        letter_picker.visible = visible;
+    }
+}
+
+fn move_letter_picker_system(
+    mut letter_picker: ResMut<LetterPicker>,
+    gamepads: Query<&Gamepad>,
+    time: Res<Time<Real>>,
+) {
+    let stick = gamepads
+        .iter()
+        .map(Gamepad::left_stick)
+        .find(|stick| stick.length() > STICK_THRESHOLD)
+        .unwrap_or_default();
+
+    let direction = if stick == Vec2::ZERO {
+        None
+    } else if stick.x.abs() > stick.y.abs() {
+        Some(IVec2::new(stick.x.signum() as i32, 0))
+    } else {
+        Some(IVec2::new(0, stick.y.signum() as i32))
+    };
+
+    if direction != letter_picker.held_direction {
+        letter_picker.held_direction = direction;
+        letter_picker.repeat_timer = Timer::new(STICK_REPEAT_DELAY, TimerMode::Once);
+
+        if let Some(direction) = direction {
+            letter_picker.step(direction);
+        }
+        return;
+    }
+
+    let Some(direction) = direction else {
+        return;
+    };
+
+    letter_picker.repeat_timer.tick(time.delta());
+    if letter_picker.repeat_timer.finished() {
+        letter_picker.repeat_timer = Timer::new(STICK_REPEAT_RATE, TimerMode::Once);
+        letter_picker.step(direction);
+    }
+}
+
+fn translate_gamepad_system(
+    gamepads: Query<&Gamepad>,
+    letter_picker: Res<LetterPicker>,
+    game_state: Res<State<GameStates>>,
+    mut actions: EventWriter<GameAction>,
+) {
+    for gamepad in &gamepads {
+        for button in gamepad.get_just_pressed() {
+            let action = match button {
+                GamepadButton::South
+                    if matches!(**game_state, GameStates::Info | GameStates::Resetting) =>
+                {
+                    GameAction::Confirm
+                }
+                GamepadButton::South => GameAction::TypeChar(letter_picker.selected_letter()),
+                GamepadButton::Select if *game_state == GameStates::Info => {
+                    GameAction::CycleDifficulty
+                }
+                GamepadButton::East => GameAction::Backspace,
+                GamepadButton::West => GameAction::Submit,
+                GamepadButton::LeftTrigger => GameAction::CursorLeft,
+                GamepadButton::RightTrigger => GameAction::CursorRight,
+                GamepadButton::DPadUp => GameAction::ScrollUp,
+                GamepadButton::DPadDown => GameAction::ScrollDown,
+                GamepadButton::Start => GameAction::Pause,
+                _ => continue,
+            };
+
+            actions.write(action);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step_from(letter: char, direction: IVec2) -> char {
+        let mut letter_picker = LetterPicker {
+            selected: LETTER_PICKER_LETTERS
+                .iter()
+                .position(|c| *c == letter)
+                .unwrap(),
+            ..default()
+        };
+        letter_picker.step(direction);
+        letter_picker.selected_letter()
+    }
+
+    #[test]
+    fn steps_to_neighbouring_letters() {
+        assert_eq!(step_from('i', IVec2::X), 'j');
+        assert_eq!(step_from('i', IVec2::NEG_X), 'h');
+        assert_eq!(step_from('i', IVec2::Y), 'b');
+        assert_eq!(step_from('i', IVec2::NEG_Y), 'p');
+    }
+
+    #[test]
+    fn wraps_around_the_edges() {
+        assert_eq!(step_from('a', IVec2::NEG_X), 'g');
+        assert_eq!(step_from('g', IVec2::X), 'a');
+        assert_eq!(step_from('a', IVec2::Y), 'v');
+        assert_eq!(step_from('v', IVec2::NEG_Y), 'a');
+    }
+
+    #[test]
+    fn skips_the_empty_cells_in_the_last_row() {
+        assert_eq!(step_from('z', IVec2::X), 'v');
+        assert_eq!(step_from('t', IVec2::NEG_Y), 'f');
+        assert_eq!(step_from('f', IVec2::Y), 't');
+    }
+}
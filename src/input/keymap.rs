@@ -0,0 +1,383 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    path::PathBuf,
+    str::FromStr,
+};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::storage::{config_dir, read_ron, write_ron};
+
+use super::GameAction;
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(Keymap::load());
+}
+
+// A key that can be bound, independent of which backend reported it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    Char(char),
+    Space,
+    Enter,
+    Tab,
+    Esc,
+    Backspace,
+    Delete,
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    F(u8),
+}
+
+// A key along with the modifiers held while pressing it, written as e.g. `ctrl+w` in the keymap.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct KeyChord {
+    pub key: Key,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+impl KeyChord {
+    pub fn new(key: Key, ctrl: bool, alt: bool, shift: bool) -> Self {
+        // Shift is already part of which character was typed, so `+` is never `shift+=`.
+        let (key, shift) = match key {
+            Key::Char(c) => (Key::Char(c.to_ascii_lowercase()), false),
+            key => (key, shift),
+        };
+
+        Self {
+            key,
+            ctrl,
+            alt,
+            shift,
+        }
+    }
+
+    fn key(key: Key) -> Self {
+        Self::new(key, false, false, false)
+    }
+
+    // Letters without modifiers are typed into the prompt, so binding them shadows typing.
+    fn shadows_typing(&self) -> bool {
+        matches!(self.key, Key::Char(c) if c.is_alphabetic()) && !self.ctrl && !self.alt
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            write!(f, "ctrl+")?;
+        }
+        if self.alt {
+            write!(f, "alt+")?;
+        }
+        if self.shift {
+            write!(f, "shift+")?;
+        }
+
+        match self.key {
+            Key::Char(c) => write!(f, "{c}"),
+            Key::Space => write!(f, "space"),
+            Key::Enter => write!(f, "enter"),
+            Key::Tab => write!(f, "tab"),
+            Key::Esc => write!(f, "esc"),
+            Key::Backspace => write!(f, "backspace"),
+            Key::Delete => write!(f, "delete"),
+            Key::Up => write!(f, "up"),
+            Key::Down => write!(f, "down"),
+            Key::Left => write!(f, "left"),
+            Key::Right => write!(f, "right"),
+            Key::Home => write!(f, "home"),
+            Key::End => write!(f, "end"),
+            Key::PageUp => write!(f, "pageup"),
+            Key::PageDown => write!(f, "pagedown"),
+            Key::F(number) => write!(f, "f{number}"),
+        }
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim().to_lowercase();
+
+        // `+` separates modifiers, so a trailing `+` is the plus key itself.
+        let (modifiers, key) = match text.strip_suffix('+') {
+            Some(rest) if rest.is_empty() || rest.ends_with('+') => (rest, "+"),
+            _ => text.rsplit_once('+').unwrap_or(("", text.as_str())),
+        };
+
+        let (mut ctrl, mut alt, mut shift) = (false, false, false);
+        for modifier in modifiers.split('+').filter(|modifier| !modifier.is_empty()) {
+            match modifier {
+                "ctrl" | "control" => ctrl = true,
+                "alt" | "option" => alt = true,
+                "shift" => shift = true,
+                _ => return Err(format!("unknown modifier `{modifier}` in `{text}`")),
+            }
+        }
+
+        let key = match key {
+            "space" => Key::Space,
+            "enter" | "return" => Key::Enter,
+            "tab" => Key::Tab,
+            "esc" | "escape" => Key::Esc,
+            "backspace" => Key::Backspace,
+            "delete" | "del" => Key::Delete,
+            "up" => Key::Up,
+            "down" => Key::Down,
+            "left" => Key::Left,
+            "right" => Key::Right,
+            "home" => Key::Home,
+            "end" => Key::End,
+            "pageup" => Key::PageUp,
+            "pagedown" => Key::PageDown,
+            _ => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Key::Char(c),
+                    (Some('f'), Some(_)) => key[1..]
+                        .parse()
+                        .map(Key::F)
+                        .map_err(|_| format!("unknown key `{key}` in `{text}`"))?,
+                    _ => return Err(format!("unknown key `{key}` in `{text}`")),
+                }
+            }
+        };
+
+        Ok(Self::new(key, ctrl, alt, shift))
+    }
+}
+
+impl TryFrom<String> for KeyChord {
+    type Error = String;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        text.parse()
+    }
+}
+
+impl From<KeyChord> for String {
+    fn from(chord: KeyChord) -> Self {
+        chord.to_string()
+    }
+}
+
+#[derive(Resource, Debug, Clone)]
+pub struct Keymap {
+    // Keys bound to each action, as listed in the keymap file.
+    bindings: BTreeMap<GameAction, Vec<KeyChord>>,
+    chords: HashMap<KeyChord, GameAction>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::from_bindings(default_bindings())
+    }
+}
+
+impl Keymap {
+    pub fn path() -> Option<PathBuf> {
+        config_dir().map(|dir| dir.join("keymap.ron"))
+    }
+
+    // Reads the keymap from the config directory, writing out the defaults if none exists yet.
+    // Actions missing from the file keep their default keys, unless the file already uses them
+    // for something else, and are written back so that the file lists every action.
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+
+        let Some(mut bindings) = read_ron::<BTreeMap<GameAction, Vec<KeyChord>>>(&path) else {
+            let bindings = default_bindings();
+            if !path.exists() {
+                write_ron(&path, &bindings);
+            }
+            return Self::from_bindings(bindings);
+        };
+
+        let used: Vec<KeyChord> = bindings.values().flatten().copied().collect();
+        let mut migrated = false;
+        for (action, mut chords) in default_bindings() {
+            if bindings.contains_key(&action) {
+                continue;
+            }
+
+            chords.retain(|chord| {
+                if used.contains(chord) {
+                    warn!("keymap: `{chord}` is already bound, so {action:?} is left without it");
+                }
+                !used.contains(chord)
+            });
+            bindings.insert(action, chords);
+            migrated = true;
+        }
+
+        if migrated {
+            write_ron(&path, &bindings);
+        }
+
+        Self::from_bindings(bindings)
+    }
+
+    fn from_bindings(mut bindings: BTreeMap<GameAction, Vec<KeyChord>>) -> Self {
+        bindings.retain(|action, _| {
+            if !action.bindable() {
+                warn!("keymap: {action:?} cannot be bound to a key, ignoring");
+            }
+            action.bindable()
+        });
+
+        let mut chords = HashMap::new();
+        for (action, action_chords) in &bindings {
+            for chord in action_chords {
+                if chord.shadows_typing() {
+                    warn!("keymap: `{chord}` is bound to {action:?}, so it can no longer be typed");
+                }
+
+                if let Some(existing) = chords.insert(*chord, *action) {
+                    warn!("keymap: `{chord}` is bound to both {existing:?} and {action:?}");
+                    chords.insert(*chord, existing);
+                }
+            }
+        }
+
+        Self { bindings, chords }
+    }
+
+    pub fn action(&self, chord: &KeyChord) -> Option<GameAction> {
+        self.chords.get(chord).copied()
+    }
+
+    // Keys bound to an action for display, e.g. `TAB` or `F4 / CTRL+S`.
+    pub fn describe(&self, action: GameAction) -> String {
+        let chords = self
+            .bindings
+            .get(&action)
+            .map(|chords| chords.as_slice())
+            .unwrap_or_default();
+
+        if chords.is_empty() {
+            return "(unbound)".into();
+        }
+
+        chords
+            .iter()
+            .map(|chord| chord.to_string().to_uppercase())
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+}
+
+fn default_bindings() -> BTreeMap<GameAction, Vec<KeyChord>> {
+    BTreeMap::from([
+        (GameAction::Backspace, vec![KeyChord::key(Key::Backspace)]),
+        (GameAction::Delete, vec![KeyChord::key(Key::Delete)]),
+        (
+            GameAction::DeleteWord,
+            vec![KeyChord::new(Key::Char('w'), true, false, false)],
+        ),
+        (
+            GameAction::DeleteToStart,
+            vec![KeyChord::new(Key::Char('u'), true, false, false)],
+        ),
+        (GameAction::CursorLeft, vec![KeyChord::key(Key::Left)]),
+        (GameAction::CursorRight, vec![KeyChord::key(Key::Right)]),
+        (GameAction::CursorHome, vec![KeyChord::key(Key::Home)]),
+        (GameAction::CursorEnd, vec![KeyChord::key(Key::End)]),
+        (GameAction::HistoryPrevious, vec![KeyChord::key(Key::Up)]),
+        (GameAction::HistoryNext, vec![KeyChord::key(Key::Down)]),
+        (GameAction::Submit, vec![KeyChord::key(Key::Enter)]),
+        (GameAction::ScrollUp, vec![KeyChord::key(Key::PageUp)]),
+        (GameAction::ScrollDown, vec![KeyChord::key(Key::PageDown)]),
+        (GameAction::ToggleSound, vec![KeyChord::key(Key::Tab)]),
+        (GameAction::ToggleDebug, vec![KeyChord::key(Key::Char('='))]),
+        (GameAction::ToggleStats, vec![KeyChord::key(Key::F(3))]),
+        (GameAction::Pause, vec![KeyChord::key(Key::Esc)]),
+        (GameAction::Confirm, vec![KeyChord::key(Key::Space)]),
+        (GameAction::CycleDifficulty, vec![KeyChord::key(Key::F(2))]),
+        (GameAction::ExportLetter, vec![KeyChord::key(Key::F(5))]),
+        (GameAction::Screenshot, vec![KeyChord::key(Key::F(12))]),
+        (GameAction::ToggleRecording, vec![KeyChord::key(Key::F(9))]),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chord(text: &str) -> KeyChord {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_plain_keys() {
+        assert_eq!(chord("tab"), KeyChord::key(Key::Tab));
+        assert_eq!(chord("pageup"), KeyChord::key(Key::PageUp));
+        assert_eq!(chord("f12"), KeyChord::key(Key::F(12)));
+        assert_eq!(chord("="), KeyChord::key(Key::Char('=')));
+    }
+
+    #[test]
+    fn parses_aliases_and_ignores_case() {
+        assert_eq!(chord("Return"), KeyChord::key(Key::Enter));
+        assert_eq!(chord(" escape "), KeyChord::key(Key::Esc));
+        assert_eq!(
+            chord("Control+W"),
+            KeyChord::new(Key::Char('w'), true, false, false)
+        );
+    }
+
+    #[test]
+    fn parses_modifiers() {
+        assert_eq!(
+            chord("ctrl+alt+shift+left"),
+            KeyChord::new(Key::Left, true, true, true)
+        );
+        assert_eq!(
+            chord("option+s"),
+            KeyChord::new(Key::Char('s'), false, true, false)
+        );
+    }
+
+    #[test]
+    fn trailing_plus_is_the_plus_key() {
+        assert_eq!(chord("+"), KeyChord::key(Key::Char('+')));
+        assert_eq!(
+            chord("ctrl++"),
+            KeyChord::new(Key::Char('+'), true, false, false)
+        );
+    }
+
+    #[test]
+    fn shift_is_dropped_from_characters() {
+        assert_eq!(chord("shift+a"), KeyChord::key(Key::Char('a')));
+        assert!(chord("shift+up").shift);
+    }
+
+    #[test]
+    fn rejects_unknown_keys_and_modifiers() {
+        assert!("hyper+a".parse::<KeyChord>().is_err());
+        assert!("banana".parse::<KeyChord>().is_err());
+        assert!("fx".parse::<KeyChord>().is_err());
+    }
+
+    #[test]
+    fn displays_what_it_parses() {
+        for text in ["ctrl+w", "alt+shift+pagedown", "f5", "+", "ctrl++"] {
+            assert_eq!(chord(text).to_string(), text);
+        }
+    }
+}
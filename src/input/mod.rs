@@ -0,0 +1,231 @@
+use bevy::prelude::*;
+use ratatui::layout::Position;
+use serde::{Deserialize, Serialize};
+
+use crate::capture::TakeScreenshot;
+#[cfg(not(feature = "windowed"))]
+use crate::capture::recording::ToggleRecording;
+use crate::export::ExportLetter;
+use crate::interface::draw::Flags;
+use crate::interface::hitboxes::Hitboxes;
+use crate::interface::widgets::letter::LetterWidgetState;
+use crate::interface::widgets::prompt::Prompt;
+use crate::settings::Settings;
+use crate::sound::SoundEffect;
+use crate::states::GameStates;
+use crate::word_checks::SubmittedWord;
+
+pub mod gamepad;
+pub mod keymap;
+#[cfg(not(feature = "windowed"))]
+mod terminal;
+#[cfg(feature = "windowed")]
+mod windowed;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_event::<GameAction>()
+        .add_plugins((
+            gamepad::plugin,
+            keymap::plugin,
+            #[cfg(not(feature = "windowed"))]
+            terminal::plugin,
+            #[cfg(feature = "windowed")]
+            windowed::plugin,
+        ))
+        .add_systems(
+            Update,
+            (
+                handle_action_system,
+                handle_prompt_action_system,
+                handle_click_action_system.run_if(in_state(GameStates::Playing)),
+                pass_info_screen_system.run_if(in_state(GameStates::Info)),
+            )
+                .after(TranslateInput),
+        );
+}
+
+// Systems that turn backend specific input events into `GameAction`s.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TranslateInput;
+
+// Everything the player can do, independent of which backend the input came from.
+#[derive(
+    Event, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum GameAction {
+    TypeChar(char),
+    Backspace,
+    Delete,
+    DeleteWord,
+    DeleteToStart,
+    CursorLeft,
+    CursorRight,
+    CursorHome,
+    CursorEnd,
+    HistoryPrevious,
+    HistoryNext,
+    Submit,
+    ScrollUp,
+    ScrollDown,
+    ToggleSound,
+    ToggleDebug,
+    ToggleStats,
+    Pause,
+    Confirm,
+    CycleDifficulty,
+    ExportLetter,
+    Screenshot,
+    ToggleRecording,
+    // Left click on a terminal cell.
+    Click { column: u16, row: u16 },
+}
+
+impl GameAction {
+    // Whether the action can be bound to keys in the keymap.
+    pub fn bindable(self) -> bool {
+        !matches!(self, GameAction::TypeChar(_) | GameAction::Click { .. })
+    }
+
+    // Whether holding the key down should keep repeating the action.
+    pub fn repeats(self) -> bool {
+        matches!(
+            self,
+            GameAction::Backspace
+                | GameAction::Delete
+                | GameAction::DeleteWord
+                | GameAction::CursorLeft
+                | GameAction::CursorRight
+        )
+    }
+
+    // Whether the action is recorded in replays. Exports and captures write files instead of
+    // changing the game, so playing them back would only write those files again.
+    pub fn replayed(self) -> bool {
+        !matches!(
+            self,
+            GameAction::ExportLetter | GameAction::Screenshot | GameAction::ToggleRecording
+        )
+    }
+}
+
+fn pass_info_screen_system(
+    mut commands: Commands,
+    mut actions: EventReader<GameAction>,
+    mut settings: ResMut<Settings>,
+) {
+    for action in actions.read() {
+        match action {
+            GameAction::Confirm => commands.set_state(GameStates::Printing),
+            GameAction::CycleDifficulty => {
+                settings.difficulty = settings.difficulty.next();
+                settings.save();
+            }
+            _ => {}
+        }
+    }
+}
+
+fn handle_action_system(
+    mut commands: Commands,
+    mut actions: EventReader<GameAction>,
+    mut flags: ResMut<Flags>,
+    mut current_letter_state: NonSendMut<LetterWidgetState>,
+    game_state: Res<State<GameStates>>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    for action in actions.read() {
+        match action {
+            GameAction::ToggleDebug => flags.debug = !flags.debug,
+            GameAction::ToggleSound => flags.sound = !flags.sound,
+            GameAction::ToggleStats => flags.stats = !flags.stats,
+            GameAction::ScrollUp => current_letter_state.scroll_state.scroll_up(),
+            GameAction::ScrollDown => current_letter_state.scroll_state.scroll_down(),
+            GameAction::Submit if *game_state == GameStates::Playing && !time.is_paused() => {
+                commands.trigger(SubmittedWord);
+            }
+            GameAction::Pause
+                if !matches!(
+                    **game_state,
+                    GameStates::Loading | GameStates::LoadingPacks | GameStates::Info
+                ) =>
+            {
+                if time.is_paused() {
+                    time.unpause();
+                } else {
+                    time.pause();
+                }
+            }
+            GameAction::ExportLetter => commands.trigger(ExportLetter),
+            GameAction::Screenshot => commands.trigger(TakeScreenshot),
+            #[cfg(not(feature = "windowed"))]
+            GameAction::ToggleRecording => commands.trigger(ToggleRecording),
+            _ => {}
+        }
+    }
+}
+
+fn handle_prompt_action_system(
+    mut commands: Commands,
+    mut actions: EventReader<GameAction>,
+    mut prompt: ResMut<Prompt>,
+    time: Res<Time<Virtual>>,
+) {
+    if time.is_paused() {
+        actions.clear();
+        return;
+    }
+
+    for action in actions.read() {
+        match action {
+            GameAction::TypeChar(c) => prompt.insert(*c),
+            GameAction::Backspace => prompt.backspace(),
+            GameAction::Delete => prompt.delete(),
+            GameAction::DeleteWord => prompt.delete_word(),
+            GameAction::DeleteToStart => prompt.delete_to_start(),
+            GameAction::CursorLeft => prompt.move_left(),
+            GameAction::CursorRight => {
+                if !prompt.accept_completion() {
+                    prompt.move_right();
+                }
+            }
+            GameAction::CursorHome => prompt.move_home(),
+            GameAction::CursorEnd => prompt.move_end(),
+            GameAction::HistoryPrevious => prompt.history_previous(),
+            GameAction::HistoryNext => prompt.history_next(),
+            _ => continue,
+        }
+
+        commands.trigger(SoundEffect::TextCharacter);
+    }
+}
+
+fn handle_click_action_system(
+    mut commands: Commands,
+    mut actions: EventReader<GameAction>,
+    mut prompt: ResMut<Prompt>,
+    hitboxes: Res<Hitboxes>,
+    settings: Res<Settings>,
+    time: Res<Time<Virtual>>,
+) {
+    if time.is_paused() {
+        actions.clear();
+        return;
+    }
+
+    for action in actions.read() {
+        let GameAction::Click { column, row } = *action else {
+            continue;
+        };
+
+        let Some(word) = hitboxes.word_at(Position::new(column, row)) else {
+            continue;
+        };
+
+        commands.trigger(SoundEffect::TextCharacter);
+        prompt.replace(word.to_string());
+
+        if settings.click_to_submit {
+            commands.trigger(SubmittedWord);
+        }
+    }
+}
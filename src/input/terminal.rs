@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+use bevy_ratatui::{
+    crossterm::event::{KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind},
+    event::{KeyEvent as RatatuiKeyEvent, MouseEvent as RatatuiMouseEvent},
+};
+
+use ratatui::layout::Position;
+
+use crate::{interface::hitboxes::Hitboxes, replay::live_input};
+
+use super::{
+    GameAction, TranslateInput,
+    keymap::{Key, KeyChord, Keymap},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (translate_keyboard_system, translate_mouse_system)
+            .in_set(TranslateInput)
+            .run_if(live_input),
+    );
+}
+
+fn translate_keyboard_system(
+    mut keyboard_input: EventReader<RatatuiKeyEvent>,
+    mut actions: EventWriter<GameAction>,
+    keymap: Res<Keymap>,
+) {
+    for event in keyboard_input.read() {
+        if event.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        let Some(chord) = key_chord(event.code, event.modifiers) else {
+            continue;
+        };
+
+        if let Some(action) = keymap.action(&chord) {
+            actions.write(action);
+        } else if let KeyCode::Char(c) = event.code {
+            if c.is_alphabetic() && !chord.ctrl && !chord.alt {
+                actions.write(GameAction::TypeChar(c.to_ascii_lowercase()));
+            }
+        }
+    }
+}
+
+fn key_chord(code: KeyCode, modifiers: KeyModifiers) -> Option<KeyChord> {
+    let mut shift = modifiers.contains(KeyModifiers::SHIFT);
+
+    let key = match code {
+        KeyCode::Char(' ') => Key::Space,
+        KeyCode::Char(c) => Key::Char(c),
+        KeyCode::Enter => Key::Enter,
+        KeyCode::Tab => Key::Tab,
+        KeyCode::BackTab => {
+            shift = true;
+            Key::Tab
+        }
+        KeyCode::Esc => Key::Esc,
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Delete => Key::Delete,
+        KeyCode::Up => Key::Up,
+        KeyCode::Down => Key::Down,
+        KeyCode::Left => Key::Left,
+        KeyCode::Right => Key::Right,
+        KeyCode::Home => Key::Home,
+        KeyCode::End => Key::End,
+        KeyCode::PageUp => Key::PageUp,
+        KeyCode::PageDown => Key::PageDown,
+        KeyCode::F(number) => Key::F(number),
+        _ => return None,
+    };
+
+    Some(KeyChord::new(
+        key,
+        modifiers.contains(KeyModifiers::CONTROL),
+        modifiers.contains(KeyModifiers::ALT),
+        shift,
+    ))
+}
+
+fn translate_mouse_system(
+    mut mouse_input: EventReader<RatatuiMouseEvent>,
+    mut actions: EventWriter<GameAction>,
+    mut hitboxes: ResMut<Hitboxes>,
+) {
+    for event in mouse_input.read() {
+        match event.kind {
+            MouseEventKind::ScrollUp => {
+                actions.write(GameAction::ScrollUp);
+            }
+            MouseEventKind::ScrollDown => {
+                actions.write(GameAction::ScrollDown);
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                actions.write(GameAction::Click {
+                    column: event.column,
+                    row: event.row,
+                });
+            }
+            MouseEventKind::Moved | MouseEventKind::Drag(_) => {
+                hitboxes.pointer = Some(Position::new(event.column, event.row));
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use bevy::{
+    input::{
+        ButtonState,
+        keyboard::{Key as LogicalKey, KeyboardInput},
+        mouse::MouseWheel,
+    },
+    prelude::*,
+    window::PrimaryWindow,
+};
+use ratatui::layout::Position;
+
+use crate::{capture::LastFrame, interface::hitboxes::Hitboxes, replay::live_input};
+
+use super::{
+    GameAction, TranslateInput,
+    keymap::{Key, KeyChord, Keymap},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (translate_keyboard_system, translate_mouse_system)
+            .in_set(TranslateInput)
+            .run_if(live_input),
+    );
+}
+
+fn translate_keyboard_system(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut actions: EventWriter<GameAction>,
+    keymap: Res<Keymap>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    let ctrl = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    let alt = keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+
+        let action = logical_key_to_key(&event.logical_key)
+            .map(|key| KeyChord::new(key, ctrl, alt, shift))
+            .and_then(|chord| keymap.action(&chord));
+
+        if let Some(action) = action {
+            // Held keys only repeat for editing the prompt.
+            if !event.repeat || action.repeats() {
+                actions.write(action);
+            }
+        } else if let Some(text) = event.text.as_ref().filter(|_| !ctrl && !alt) {
+            for c in text.chars().filter(|c| c.is_alphabetic()) {
+                actions.write(GameAction::TypeChar(c.to_ascii_lowercase()));
+            }
+        }
+    }
+}
+
+// Uses the logical key, so that bindings follow the keyboard layout rather than key positions.
+fn logical_key_to_key(logical_key: &LogicalKey) -> Option<Key> {
+    Some(match logical_key {
+        LogicalKey::Space => Key::Space,
+        LogicalKey::Character(text) => Key::Char(text.chars().next()?),
+        LogicalKey::Enter => Key::Enter,
+        LogicalKey::Tab => Key::Tab,
+        LogicalKey::Escape => Key::Esc,
+        LogicalKey::Backspace => Key::Backspace,
+        LogicalKey::Delete => Key::Delete,
+        LogicalKey::ArrowUp => Key::Up,
+        LogicalKey::ArrowDown => Key::Down,
+        LogicalKey::ArrowLeft => Key::Left,
+        LogicalKey::ArrowRight => Key::Right,
+        LogicalKey::Home => Key::Home,
+        LogicalKey::End => Key::End,
+        LogicalKey::PageUp => Key::PageUp,
+        LogicalKey::PageDown => Key::PageDown,
+        LogicalKey::F1 => Key::F(1),
+        LogicalKey::F2 => Key::F(2),
+        LogicalKey::F3 => Key::F(3),
+        LogicalKey::F4 => Key::F(4),
+        LogicalKey::F5 => Key::F(5),
+        LogicalKey::F6 => Key::F(6),
+        LogicalKey::F7 => Key::F(7),
+        LogicalKey::F8 => Key::F(8),
+        LogicalKey::F9 => Key::F(9),
+        LogicalKey::F10 => Key::F(10),
+        LogicalKey::F11 => Key::F(11),
+        LogicalKey::F12 => Key::F(12),
+        _ => return None,
+    })
+}
+
+fn translate_mouse_system(
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    mut actions: EventWriter<GameAction>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    last_frame: Res<LastFrame>,
+    mut hitboxes: ResMut<Hitboxes>,
+) {
+    for event in mouse_wheel_events.read() {
+        if event.y > 0.0 {
+            actions.write(GameAction::ScrollUp);
+        } else if event.y < 0.0 {
+            actions.write(GameAction::ScrollDown);
+        }
+    }
+
+    // The terminal is stretched over the whole window, so cells are found by proportion.
+    hitboxes.pointer = window.cursor_position().map(|cursor| {
+        let area = last_frame.area;
+        Position::new(
+            (cursor.x / window.width() * area.width as f32) as u16,
+            (cursor.y / window.height() * area.height as f32) as u16,
+        )
+    });
+
+    if let Some(Position { x, y }) = hitboxes.pointer {
+        if mouse_buttons.just_pressed(MouseButton::Left) {
+            actions.write(GameAction::Click { column: x, row: y });
+        }
+    }
+}
@@ -1,37 +1,60 @@
 use std::ops::DerefMut;
 
-use bevy::{diagnostic::DiagnosticsStore, prelude::*};
+use bevy::{diagnostic::DiagnosticsStore, ecs::system::SystemParam, prelude::*};
 use bevy_ratatui::RatatuiContext;
 use bevy_ratatui_camera::RatatuiCameraWidget;
 use ratatui::{
+    buffer::Buffer,
     layout::{Constraint, Layout, Rect},
     style::Stylize,
-    text::{Line, Span, Text},
-    widgets::{Block, BorderType, Padding, Paragraph, StatefulWidget, Widget, Wrap},
+    text::{Line, Span},
+    widgets::{Block, Padding, StatefulWidget, Widget, WidgetRef},
 };
 use tachyonfx::{Effect, Interpolation, Shader, fx};
 
 use crate::{
+    achievements::AchievementToasts,
+    analytics::Analytics,
+    capture::{FrameCapture, LastFrame},
     constants::{
-        MAC_PURPLE_COLOR, MAC_RED_COLOR, MAC_YELLOW_COLOR, PLASTIC_MEDIUM_BACKGROUND_COLOR,
+        MAC_CYAN_COLOR, MAC_RED_COLOR, MAC_YELLOW_COLOR, PLASTIC_DARK_BACKGROUND_COLOR,
+        URGENCY_BLINK_START, URGENCY_RAMP_START,
     },
+    input::{GameAction, gamepad::LetterPicker, keymap::Keymap},
     letters::CurrentLetter,
-    scene::spawning::WordCube,
+    metrics::Metrics,
+    scene::{
+        camera::RenderStyle,
+        objects::SceneObject,
+        spawning::{Urgency, WordCube},
+        word_mesh::WordMeshesActive,
+    },
+    settings::Settings,
     states::{GameStates, Statistics},
 };
 
 use super::{
+    hitboxes::Hitboxes,
     layout::layout_frame,
+    post_process::{
+        FillText, Glitch, LetterFillScroll, ScenePass, apply_scene_passes, letter_fill_color,
+    },
     widgets::{
-        confetti::{Confetti, ConfettiWidget},
+        confetti::{Confetti, ConfettiWidget, ParticleOrigin},
+        info::InfoWidget,
         letter::{LetterWidget, LetterWidgetState},
+        letter_picker::LetterPickerWidget,
+        metrics::{TallyWidget, TypingStatsWidget},
+        paused::PausedWidget,
         prompt::{Prompt, PromptState},
+        toast::ToastWidget,
     },
 };
 
 pub fn plugin(app: &mut App) {
     app.init_resource::<Flags>()
         .insert_non_send_resource(ResetEffect::default())
+        .insert_non_send_resource(ToastEffect::default())
         .add_systems(Update, draw_system)
         .add_systems(OnEnter(GameStates::Resetting), activate_reset_scene_effect)
         .add_systems(OnExit(GameStates::Printing), deactivate_reset_scene_effect);
@@ -41,6 +64,8 @@ pub fn plugin(app: &mut App) {
 pub struct Flags {
     pub debug: bool,
     pub sound: bool,
+    // Whether the typing stats screen is open.
+    pub stats: bool,
 }
 
 impl Default for Flags {
@@ -48,10 +73,41 @@ impl Default for Flags {
         Self {
             debug: false,
             sound: true,
+            stats: false,
         }
     }
 }
 
+// Resources for panels drawn over the game, grouped to keep `draw_system` under the parameter limit.
+#[derive(SystemParam)]
+struct Overlays<'w> {
+    keymap: Res<'w, Keymap>,
+    letter_picker: Res<'w, LetterPicker>,
+    settings: Res<'w, Settings>,
+    metrics: Res<'w, Metrics>,
+    analytics: Res<'w, Analytics>,
+}
+
+// Scene objects and effects drawn over the game, grouped like `Overlays`.
+#[derive(SystemParam)]
+struct SceneEffects<'w, 's> {
+    scene_objects: Query<'w, 's, (&'static SceneObject, &'static Transform)>,
+    toasts: Res<'w, AchievementToasts>,
+    toast_effect: NonSendMut<'w, ToastEffect>,
+    fill_scroll: Res<'w, LetterFillScroll>,
+    glitch: Res<'w, Glitch>,
+    fixed_time: Res<'w, Time<Fixed>>,
+    word_meshes_active: Res<'w, WordMeshesActive>,
+}
+
+// Resources written from the drawn frame, for captures and for input to check against.
+#[derive(SystemParam)]
+struct FrameOutputs<'w> {
+    last_frame: ResMut<'w, LastFrame>,
+    capture: ResMut<'w, FrameCapture>,
+    hitboxes: ResMut<'w, Hitboxes>,
+}
+
 fn draw_system(
     mut ratatui: ResMut<RatatuiContext>,
     flags: Res<Flags>,
@@ -60,62 +116,34 @@ fn draw_system(
     current_letter: Option<Res<CurrentLetter>>,
     mut current_letter_state: NonSendMut<LetterWidgetState>,
     camera: Single<(&Camera, &GlobalTransform, &mut RatatuiCameraWidget)>,
-    stars: Query<(&WordCube, &Transform)>,
+    stars: Query<(&WordCube, &Transform, &Urgency)>,
     prompt: Res<Prompt>,
     mut prompt_state: ResMut<PromptState>,
     confettis: Query<&Confetti>,
     reset_effect: NonSendMut<ResetEffect>,
     stats: Res<Statistics>,
-    time: Res<Time>,
+    time: Res<Time<Virtual>>,
+    overlays: Overlays,
+    mut effects: SceneEffects,
+    mut outputs: FrameOutputs,
 ) -> Result {
     let (camera, camera_transform, camera_widget) = camera.into_inner();
     let mut camera_widget = camera_widget.into_inner();
 
-    ratatui.draw(|frame| {
+    let completed_frame = ratatui.draw(|frame| {
         let show_log_panel = !cfg!(feature = "windowed");
         let area = layout_frame(frame, &flags, &diagnostics, &stats, show_log_panel);
 
         let buf = frame.buffer_mut();
 
-        if *state == GameStates::Info {
-            let outer_block = Block::default().padding(Padding::proportional(2));
-            let info_block = Block::bordered()
-                .border_type(BorderType::Double)
-                .padding(Padding::proportional(2))
-                .bg(PLASTIC_MEDIUM_BACKGROUND_COLOR);
-
-            let info_paragraph = Paragraph::new(Text::from(vec![
-                Line::from("HOW TO PLAY").bold().fg(MAC_PURPLE_COLOR),
-                Line::from(""),
-                Line::from(
-                    "Each round, a chain letter will appear on the left. Each letter has a list of \
-                    blessings, for if the letter is forwarded, and a list of curses, for if the \
-                    chain is broken. Each blessing and curse has a missing word. Your job is to \
-                    look at the pool of words moving past on the right side of the screen, and \
-                    figure out which ones correspond with the blessings.",
-                ),
-                Line::from(""),
-                Line::from(
-                    "Type your word and press enter. Matching blessings bestow money or score, \
-                    curses take it away, and decoys do nothing (currently). Collect all the \
-                    blessings to collect your income and move to the next round.",
-                ),
-                Line::from(""),
-                Line::from("Someday the money will do something. Today is not that day."),
-                Line::from(""),
-                Line::from("Press TAB to toggle sound.").fg(MAC_RED_COLOR),
-                Line::from(""),
-                Line::from("PRESS SPACE TO BEGIN")
-                    .bold()
-                    .fg(MAC_PURPLE_COLOR),
-            ]))
-            .wrap(Wrap { trim: true });
-
-            let inner_area = outer_block.inner(area);
-            let inner_inner_area = info_block.inner(inner_area);
-            info_block.render(inner_area, buf);
-            info_paragraph.render(inner_inner_area, buf);
-
+        // Pack files usually finish loading within a frame or two, so they share the info screen.
+        if matches!(*state, GameStates::LoadingPacks | GameStates::Info) {
+            InfoWidget {
+                keymap: &overlays.keymap,
+                settings: &overlays.settings,
+            }
+            .render(area, buf);
+            draw_stats_screen(&flags, &overlays, area, buf);
             return;
         }
 
@@ -136,8 +164,45 @@ fn draw_system(
             .padding(Padding::new(0, 2, 0, 1))
             .inner(prompt_area);
 
+        // Obstacles as the center, radius (in cells) and depth of the circle they cover, and
+        // power-ups as labels showing their command words.
+        let mut obstacles = vec![];
         let mut star_widgets = vec![];
-        for (star, star_transform) in &stars {
+        for (scene_object, object_transform) in &effects.scene_objects {
+            let Some(ndc_coords) =
+                camera.world_to_ndc(camera_transform, object_transform.translation)
+            else {
+                continue;
+            };
+
+            let center = camera_widget.ndc_to_cell(scene_area, ndc_coords);
+
+            if let Some(command) = scene_object.command() {
+                star_widgets.push((
+                    Line::from(command.to_string())
+                        .bold()
+                        .fg(PLASTIC_DARK_BACKGROUND_COLOR)
+                        .bg(MAC_CYAN_COLOR),
+                    Rect::new(
+                        (center.x.max(0) as u16).saturating_sub(command.len() as u16 / 2) + 1,
+                        center.y as u16,
+                        command.len() as u16,
+                        1,
+                    ),
+                    command,
+                    true,
+                ));
+            } else if let Some(edge_ndc_coords) = camera.world_to_ndc(
+                camera_transform,
+                object_transform.translation + Vec3::X * scene_object.radius,
+            ) {
+                let edge = camera_widget.ndc_to_cell(scene_area, edge_ndc_coords);
+                let radius = (edge.x as f32 - center.x as f32).abs();
+                obstacles.push((center, radius, object_transform.translation.z));
+            }
+        }
+
+        for (star, star_transform, urgency) in &stars {
             let Some(ndc_coords) =
                 camera.world_to_ndc(camera_transform, star_transform.translation)
             else {
@@ -145,12 +210,23 @@ fn draw_system(
             };
 
             let position = camera_widget.ndc_to_cell(scene_area, ndc_coords);
+
+            // Cells are about twice as tall as they are wide, so rows count double.
+            let occluded = obstacles.iter().any(|(center, radius, depth)| {
+                let x = position.x as f32 - center.x as f32;
+                let y = (position.y as f32 - center.y as f32) * 2.;
+                *depth > star_transform.translation.z && x * x + y * y <= radius * radius
+            });
+            if occluded {
+                continue;
+            }
+
             let Srgba {
                 red: lighter_red,
                 blue: lighter_blue,
                 green: lighter_green,
                 ..
-            } = star.color.lighter(0.3).to_srgba();
+            } = urgent_color(star.color.lighter(0.3), urgency.level).to_srgba();
             let lighter_color = ratatui::style::Color::Rgb(
                 (lighter_red * 256.) as u8,
                 (lighter_green * 256.) as u8,
@@ -175,20 +251,38 @@ fn draw_system(
             } else {
                 Line::from(star.word.clone()).fg(lighter_color)
             };
+            // Every word gets the cue as it nears the bottom, so that it does not give blessings away.
+            let blinking = urgency.level >= URGENCY_BLINK_START
+                && !overlays.settings.reduced_motion
+                && (time.elapsed_secs() * 4.) as u32 % 2 == 0;
+            let star_line = if blinking {
+                star_line.bg(darker_color).reversed()
+            } else {
+                star_line.bg(darker_color)
+            };
             star_widgets.push((
-                star_line.bg(darker_color),
+                star_line,
                 Rect::new(
-                    position.x as u16 - (star.word.len() as u16 / 2) + 1,
+                    (position.x.max(0) as u16).saturating_sub(star.word.len() as u16 / 2) + 1,
                     position.y as u16,
                     star.word.len() as u16,
                     1,
                 ),
+                star.word.as_str(),
+                // Words spelled out in the scene keep their hitboxes, but not their labels.
+                !effects.word_meshes_active.0,
             ));
         }
 
         let mut confetti_widgets = vec![];
         for confetti in &confettis {
-            let Some(ndc_coords) = camera.world_to_ndc(camera_transform, confetti.position) else {
+            let ParticleOrigin::World(origin) = confetti.origin else {
+                continue;
+            };
+            let Some(ndc_coords) = camera.world_to_ndc(
+                camera_transform,
+                origin + confetti.interpolated_position(effects.fixed_time.overstep_fraction()),
+            ) else {
                 continue;
             };
 
@@ -197,19 +291,34 @@ fn draw_system(
             confetti_widgets.push(ConfettiWidget::new(confetti, cell));
         }
 
+        outputs.hitboxes.word_labels.clear();
+        outputs.hitboxes.testimonials.clear();
+        outputs.hitboxes.scene_area = scene_area;
+        outputs.hitboxes.letter_area = left_area;
+
         if let Some(current_letter) = current_letter {
+            current_letter_state.pointer = outputs.hitboxes.pointer;
             LetterWidget(&current_letter.0).render(left_area, buf, &mut current_letter_state);
+            for (area, word) in &current_letter_state.testimonial_areas {
+                let relative_area = Rect {
+                    x: area.x - left_area.x,
+                    y: area.y - left_area.y,
+                    ..*area
+                };
+                outputs
+                    .hitboxes
+                    .testimonials
+                    .push((relative_area, word.clone()));
+            }
 
-            let character_pool: Vec<_> = current_letter
-                .interpolated_flavor
-                .body
-                .chars()
-                .chain([' '].into_iter())
-                .chain(current_letter.interpolated_flavor.signoff.chars())
-                .chain([' '].into_iter())
-                .chain(current_letter.flavor.footer.chars())
-                .chain([' '].into_iter())
-                .collect();
+            for confetti in &confettis {
+                if let Some(cell) = confetti.letter_cell(effects.fixed_time.overstep_fraction()) {
+                    let cell = cell + IVec2::new(left_area.x as i32, left_area.y as i32);
+                    ConfettiWidget::new(confetti, cell).render_ref(left_area, buf);
+                }
+            }
+
+            let fill_text = FillText::new(&current_letter);
 
             Widget::render(camera_widget.deref_mut(), scene_area, buf);
 
@@ -217,16 +326,22 @@ fn draw_system(
                 camera_widget.render_overlay(scene_area, buf, confetti_widget);
             }
 
-            for position in scene_area.positions() {
-                if buf[position].symbol() == "@" {
-                    let _offset = (prompt.timer.remaining_secs() * 14.0) as u16;
-                    let index = position.x + position.y * scene_area.width;
-                    let wrapped_index = index as usize % character_pool.len();
-                    buf[position].set_char(character_pool[wrapped_index]);
-                }
+            let mut scene_passes = vec![];
+            if overlays.settings.render_style == RenderStyle::LetterText {
+                scene_passes.push(ScenePass::LetterFill {
+                    fill: RenderStyle::FILL_CHARACTER,
+                    text: &fill_text,
+                    offset: effects.fill_scroll.offset(),
+                    color: letter_fill_color(
+                        prompt.timer.fraction(),
+                        effects.fill_scroll.offset(),
+                        overlays.settings.reduced_motion,
+                    ),
+                });
             }
+            apply_scene_passes(&scene_passes, scene_area, buf);
 
-            for (star_widget, star_area) in &star_widgets {
+            for (star_widget, star_area, word, drawn) in &star_widgets {
                 if scene_area.contains((star_area.x, star_area.y).into())
                     && scene_area.contains(
                         (
@@ -236,7 +351,13 @@ fn draw_system(
                             .into(),
                     )
                 {
-                    star_widget.render(*star_area, buf);
+                    if *drawn {
+                        star_widget.render(*star_area, buf);
+                    }
+                    outputs
+                        .hitboxes
+                        .word_labels
+                        .push((*star_area, word.to_string()));
                 }
             }
         };
@@ -246,14 +367,119 @@ fn draw_system(
         }
 
         prompt.render(prompt_area, buf, &mut prompt_state);
+
+        if overlays.letter_picker.visible && *state == GameStates::Playing {
+            LetterPickerWidget(&overlays.letter_picker).render(scene_area, buf);
+        }
+
+        if *state == GameStates::Resetting {
+            TallyWidget {
+                metrics: &overlays.metrics.letter,
+                continue_keys: overlays.keymap.describe(GameAction::Confirm),
+            }
+            .render(scene_area, buf);
+        }
+
+        if time.is_paused() {
+            PausedWidget.render(scene_area, buf);
+        }
+
+        draw_toast(
+            &effects.toasts,
+            &mut effects.toast_effect,
+            &time,
+            scene_area,
+            buf,
+        );
+
+        draw_stats_screen(&flags, &overlays, area, buf);
+
+        // Damage corrupts whatever ended up on screen, so it goes after everything else.
+        if effects.glitch.intensity() > 0. {
+            apply_scene_passes(
+                &[ScenePass::Glitch {
+                    intensity: effects.glitch.intensity(),
+                    seed: effects.glitch.seed(),
+                    reduced_motion: overlays.settings.reduced_motion,
+                }],
+                area,
+                buf,
+            );
+        }
     })?;
 
+    // Copying the whole frame is only worth it when a screenshot or recording is going to save it.
+    outputs.capture.copied = outputs.capture.wanted();
+    if outputs.capture.copied {
+        outputs.last_frame.clone_from(completed_frame.buffer);
+    } else if outputs.last_frame.area != completed_frame.buffer.area {
+        outputs.last_frame.resize(completed_frame.buffer.area);
+    }
+
     Ok(())
 }
 
+// Ramps a word label's color towards red as its cube nears the bottom of the screen.
+fn urgent_color(color: Color, urgency: f32) -> Color {
+    let ramp = ((urgency - URGENCY_RAMP_START) / (1. - URGENCY_RAMP_START)).clamp(0., 1.);
+    let ratatui::style::Color::Rgb(red, green, blue) = MAC_RED_COLOR else {
+        return color;
+    };
+
+    color
+        .to_srgba()
+        .mix(&Srgba::rgb_u8(red, green, blue), ramp)
+        .into()
+}
+
+fn draw_stats_screen(flags: &Flags, overlays: &Overlays, area: Rect, buf: &mut Buffer) {
+    if flags.stats {
+        TypingStatsWidget {
+            run: &overlays.metrics.run,
+            lifetime: &overlays.metrics.lifetime,
+            analytics: &overlays.analytics.summary,
+        }
+        .render(area, buf);
+    }
+}
+
+fn draw_toast(
+    toasts: &AchievementToasts,
+    toast_effect: &mut ToastEffect,
+    time: &Time<Virtual>,
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let Some(achievement) = toasts.current() else {
+        *toast_effect = ToastEffect::default();
+        return;
+    };
+
+    // Each achievement only unlocks once, so a new id means a new toast.
+    if toast_effect.id.as_ref() != Some(&achievement.id) {
+        *toast_effect = ToastEffect {
+            id: Some(achievement.id.clone()),
+            effect: Some(Effect::new(fx::coalesce((500, Interpolation::QuadOut)))),
+        };
+    }
+
+    ToastWidget(achievement).render(area, buf);
+
+    if let Some(ref mut effect) = toast_effect.effect {
+        effect.process(time.delta().into(), buf, ToastWidget::area(area));
+    }
+}
+
 #[derive(Deref, DerefMut, Default)]
 pub struct ResetEffect(pub Option<Effect>);
 
+// Effect revealing the current achievement toast, with the id of the achievement it was made for.
+#[derive(Default)]
+pub struct ToastEffect {
+    id: Option<String>,
+    effect: Option<Effect>,
+}
+
 fn activate_reset_scene_effect(mut reset_effect: NonSendMut<ResetEffect>) {
     #[cfg(not(feature = "windowed"))]
     {
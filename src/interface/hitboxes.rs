@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use ratatui::layout::{Position, Rect};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<Hitboxes>();
+}
+
+// Where things were drawn on the last frame, so that mouse input can be matched against them.
+#[derive(Resource, Default, Debug)]
+pub struct Hitboxes {
+    // Cell under the mouse, if it has moved over the terminal.
+    pub pointer: Option<Position>,
+    // Word labels drawn over the scene, in drawing order.
+    pub word_labels: Vec<(Rect, String)>,
+    // Areas of the scene and the letter widget.
+    pub scene_area: Rect,
+    pub letter_area: Rect,
+    // Testimonial lines visible in the letter, relative to the letter area, with their words.
+    pub testimonials: Vec<(Rect, String)>,
+}
+
+impl Hitboxes {
+    // Topmost word label covering a cell.
+    pub fn word_at(&self, position: Position) -> Option<&str> {
+        self.word_labels
+            .iter()
+            .rev()
+            .find(|(area, _)| area.contains(position))
+            .map(|(_, word)| word.as_str())
+    }
+
+    // Cell in the middle of a testimonial's first row, relative to the letter area.
+    pub fn testimonial_cell(&self, word: &str) -> Option<IVec2> {
+        self.testimonials
+            .iter()
+            .find(|(_, target_word)| target_word == word)
+            .map(|(area, _)| IVec2::new((area.x + area.width / 2) as i32, area.y as i32))
+    }
+}
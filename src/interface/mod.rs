@@ -1,10 +1,17 @@
 use bevy::prelude::*;
 
 pub mod draw;
+pub mod hitboxes;
 pub mod layout;
+pub mod post_process;
 pub mod utilities;
 pub mod widgets;
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_plugins((draw::plugin, widgets::plugin));
+    app.add_plugins((
+        draw::plugin,
+        hitboxes::plugin,
+        post_process::plugin,
+        widgets::plugin,
+    ));
 }
@@ -0,0 +1,303 @@
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use ratatui::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    style::{Color, Style, Stylize},
+};
+
+use crate::{
+    constants::{
+        GLITCH_GLYPHS, GLITCH_TIME, LETTER_FILL_SPEED, MAC_CYAN_COLOR, MAC_GREEN_COLOR,
+        MAC_RED_COLOR, MAC_YELLOW_COLOR, PLASTIC_PRIMARY_COLOR,
+    },
+    interface::{utilities::lerp_color, widgets::prompt::Prompt},
+    letters::{Letter, Testimonial},
+    states::GameStates,
+    word_checks::CurseGuessed,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<LetterFillScroll>()
+        .init_resource::<Glitch>()
+        .add_observer(curse_guessed_observer)
+        .add_systems(Update, tick_glitch_system)
+        .add_systems(OnEnter(GameStates::Printing), reset_letter_fill_system)
+        .add_systems(
+            Update,
+            scroll_letter_fill_system.run_if(in_state(GameStates::Playing)),
+        );
+}
+
+// Stage run over part of the frame once it has been drawn. Passes are applied in order, so each
+// one sees the output of the last.
+pub enum ScenePass<'a> {
+    // Replaces every fill character with text from the current letter, so that lit surfaces
+    // read as the letter being passed along.
+    LetterFill {
+        fill: char,
+        text: &'a FillText,
+        // Characters the text has scrolled by.
+        offset: usize,
+        // Color of characters that are not highlighted.
+        color: Color,
+    },
+    // Corrupts the frame after taking damage, with shifted rows, swapped glyphs, swapped color
+    // channels and a torn band. Reduced motion keeps only the color swapping.
+    Glitch {
+        intensity: f32,
+        seed: u64,
+        reduced_motion: bool,
+    },
+}
+
+impl ScenePass<'_> {
+    fn apply(&self, area: Rect, buf: &mut Buffer) {
+        match self {
+            ScenePass::LetterFill {
+                fill,
+                text,
+                offset,
+                color,
+            } => {
+                if text.chars.is_empty() {
+                    return;
+                }
+
+                let fill = fill.to_string();
+                for position in area.positions() {
+                    if buf[position].symbol() == fill {
+                        let index = position.x as usize
+                            + position.y as usize * area.width as usize
+                            + offset;
+                        let wrapped_index = index % text.chars.len();
+                        let cell = &mut buf[position];
+                        cell.set_char(text.chars[wrapped_index]);
+
+                        match text.highlights[wrapped_index] {
+                            Some(highlight) => cell.set_style(Style::new().fg(highlight).bold()),
+                            None => cell.set_fg(*color),
+                        };
+                    }
+                }
+            }
+            ScenePass::Glitch {
+                intensity,
+                seed,
+                reduced_motion,
+            } => {
+                let mut rng = ChaCha8Rng::seed_from_u64(*seed);
+                let intensity = intensity.clamp(0., 1.);
+
+                for position in area.positions() {
+                    if rng.gen_bool((intensity * 0.5) as f64) {
+                        let cell = &mut buf[position];
+                        let (fg, bg) = (swap_channels(cell.fg), swap_channels(cell.bg));
+                        cell.set_fg(fg).set_bg(bg);
+                    }
+                }
+
+                if *reduced_motion {
+                    return;
+                }
+
+                let max_shift = (intensity * 6.).ceil() as i32;
+                for y in area.top()..area.bottom() {
+                    if rng.gen_bool((intensity * 0.3) as f64) {
+                        let shift = rng.gen_range(-max_shift..=max_shift);
+                        shift_row(buf, area, y, shift);
+                    }
+                }
+
+                for position in area.positions() {
+                    if rng.gen_bool((intensity * 0.05) as f64) {
+                        let glyph = GLITCH_GLYPHS[rng.gen_range(0..GLITCH_GLYPHS.len())];
+                        buf[position].set_char(glyph);
+                    }
+                }
+
+                // A band of rows torn off and repeated a little further along.
+                if area.height > 1 {
+                    let band_top = rng.gen_range(area.top()..area.bottom() - 1);
+                    let band_height = rng.gen_range(1..=(1. + intensity * 3.) as u16);
+                    let shift = rng.gen_range(1..=max_shift.max(1));
+                    for y in band_top..(band_top + band_height).min(area.bottom()) {
+                        let source: Vec<Cell> = (area.left()..area.right())
+                            .map(|x| buf[(x, band_top)].clone())
+                            .collect();
+                        for (index, x) in (area.left()..area.right()).enumerate() {
+                            let source_index =
+                                (index as i32 - shift).rem_euclid(source.len() as i32);
+                            buf[(x, y)] = source[source_index as usize].clone();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Rotates a row of the area by a number of cells, wrapping around at the edges.
+fn shift_row(buf: &mut Buffer, area: Rect, y: u16, shift: i32) {
+    let row: Vec<Cell> = (area.left()..area.right())
+        .map(|x| buf[(x, y)].clone())
+        .collect();
+    if row.is_empty() {
+        return;
+    }
+
+    for (index, x) in (area.left()..area.right()).enumerate() {
+        let source_index = (index as i32 - shift).rem_euclid(row.len() as i32);
+        buf[(x, y)] = row[source_index as usize].clone();
+    }
+}
+
+fn swap_channels(color: Color) -> Color {
+    match color {
+        Color::Rgb(red, green, blue) => Color::Rgb(green, blue, red),
+        Color::Reset => Color::Reset,
+        _ => Color::Magenta,
+    }
+}
+
+pub fn apply_scene_passes(passes: &[ScenePass], area: Rect, buf: &mut Buffer) {
+    for pass in passes {
+        pass.apply(area, buf);
+    }
+}
+
+// Text of a letter to fill the scene with, and the color of every character that belongs to a
+// guessed testimonial word.
+pub struct FillText {
+    pub chars: Vec<char>,
+    pub highlights: Vec<Option<Color>>,
+}
+
+impl FillText {
+    pub fn new(letter: &Letter) -> Self {
+        let mut fill_text = Self {
+            chars: vec![],
+            highlights: vec![],
+        };
+
+        fill_text.push(&letter.interpolated_flavor.body, None);
+        for blessing in &letter.blessings {
+            fill_text.push_testimonial(blessing, MAC_GREEN_COLOR);
+        }
+        for curse in &letter.curses {
+            fill_text.push_testimonial(curse, MAC_RED_COLOR);
+        }
+        fill_text.push(&letter.interpolated_flavor.signoff, None);
+        fill_text.push(&letter.flavor.footer, None);
+
+        fill_text
+    }
+
+    fn push(&mut self, text: &str, highlight: Option<Color>) {
+        for character in text.chars().chain([' ']) {
+            self.chars.push(character);
+            self.highlights.push(highlight);
+        }
+    }
+
+    fn push_testimonial(&mut self, testimonial: &Testimonial, highlight: Color) {
+        let start = self.chars.len();
+        self.push(&testimonial.revealed_message(), None);
+
+        if !testimonial.collected {
+            return;
+        }
+
+        // The revealed word sits where the blank was, which is the same length.
+        let blank = "_".repeat(testimonial.target_word.len());
+        if let Some(byte_index) = testimonial.message.find(&blank) {
+            let word_start = start + testimonial.message[..byte_index].chars().count();
+            let word_end = word_start + testimonial.target_word.chars().count();
+            for index in word_start..word_end {
+                self.highlights[index] = Some(highlight);
+            }
+        }
+    }
+}
+
+// How far the letter fill has scrolled, which speeds up as the timer runs out.
+#[derive(Resource, Debug, Default)]
+pub struct LetterFillScroll {
+    offset: f32,
+}
+
+impl LetterFillScroll {
+    pub fn offset(&self) -> usize {
+        self.offset as usize
+    }
+}
+
+// Screen corruption after guessing a curse, fading out over its timer.
+#[derive(Resource, Debug, Default)]
+pub struct Glitch {
+    timer: Timer,
+    severity: f32,
+    // Changes every frame, so that the corruption moves around.
+    seed: u64,
+}
+
+impl Glitch {
+    pub fn intensity(&self) -> f32 {
+        if self.timer.finished() {
+            return 0.;
+        }
+
+        self.severity * self.timer.fraction_remaining()
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+// Color of the letter fill for how much of the timer has run out, from calm to frantic. Reduced
+// motion skips the flickering at the very end.
+pub fn letter_fill_color(pressure: f32, offset: usize, reduced_motion: bool) -> Color {
+    if pressure < 0.6 {
+        lerp_color(MAC_CYAN_COLOR, MAC_YELLOW_COLOR, pressure / 0.6)
+    } else if pressure < 0.9 || reduced_motion || offset / 3 % 2 == 0 {
+        lerp_color(
+            MAC_YELLOW_COLOR,
+            MAC_RED_COLOR,
+            ((pressure - 0.6) / 0.3).min(1.),
+        )
+    } else {
+        PLASTIC_PRIMARY_COLOR
+    }
+}
+
+fn reset_letter_fill_system(mut scroll: ResMut<LetterFillScroll>) {
+    *scroll = LetterFillScroll::default();
+}
+
+fn scroll_letter_fill_system(
+    mut scroll: ResMut<LetterFillScroll>,
+    prompt: Res<Prompt>,
+    time: Res<Time>,
+) {
+    let pressure = prompt.timer.fraction();
+    scroll.offset += time.delta_secs() * LETTER_FILL_SPEED * (1. + 4. * pressure * pressure);
+}
+
+fn curse_guessed_observer(trigger: Trigger<CurseGuessed>, mut glitch: ResMut<Glitch>) {
+    // Even the mildest curse should be noticeable.
+    let severity = trigger.event().0.severity().max(0.25);
+
+    glitch.severity = severity.max(glitch.intensity());
+    glitch.timer = Timer::from_seconds(GLITCH_TIME, TimerMode::Once);
+}
+
+fn tick_glitch_system(mut glitch: ResMut<Glitch>, time: Res<Time<Real>>) {
+    if glitch.timer.finished() {
+        return;
+    }
+
+    glitch.timer.tick(time.delta());
+    glitch.seed = glitch.seed.wrapping_add(1);
+}
@@ -1,5 +1,5 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect, Size};
-use ratatui::style::Style;
+use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span};
 
 use crate::constants::{MAC_CYAN_COLOR, MAC_GREEN_COLOR, PLASTIC_PRIMARY_COLOR};
@@ -119,3 +119,19 @@ pub fn interpolate_and_truncate(
 
     Line::from(spans)
 }
+
+// Blends between two RGB colors, keeping `from` for any other kind of color.
+pub fn lerp_color(from: Color, to: Color, amount: f32) -> Color {
+    let (Color::Rgb(from_red, from_green, from_blue), Color::Rgb(to_red, to_green, to_blue)) =
+        (from, to)
+    else {
+        return from;
+    };
+    let lerp = |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * amount) as u8;
+
+    Color::Rgb(
+        lerp(from_red, to_red),
+        lerp(from_green, to_green),
+        lerp(from_blue, to_blue),
+    )
+}
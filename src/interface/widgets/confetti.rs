@@ -1,34 +1,219 @@
-use std::f32::consts::PI;
+use std::f32::consts::TAU;
 
 use bevy::prelude::*;
-use rand::RngCore;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_common_assets::ron::RonAssetPlugin;
+use rand::Rng;
 use ratatui::{
     buffer::Buffer,
     layout::{Position, Rect},
-    style::{Style, Stylize},
+    style::{Color, Style, Stylize},
     widgets::WidgetRef,
 };
+use serde::Deserialize;
 
-use crate::{constants::CONFETTI_AMOUNT, rng::RngResource, states::GameStates};
+use crate::{
+    constants::{LETTER_CONFETTI_SCALE, MAC_YELLOW_COLOR},
+    interface::{hitboxes::Hitboxes, utilities::lerp_color},
+    rng::RngResource,
+    states::{GameStates, LetterCleared},
+};
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_observer(confetti_spawn_observer).add_systems(
-        Update,
-        (
-            move_confetti_system.run_if(in_state(GameStates::Playing)),
-            move_confetti_system.run_if(in_state(GameStates::Resetting)),
-            despawn_confetti_system,
-        ),
-    );
+    app.add_plugins(RonAssetPlugin::<Emitter>::new(&["emitter.ron"]))
+        .add_observer(confetti_spawn_observer)
+        .add_observer(letter_cleared_confetti_observer)
+        .add_systems(
+            FixedUpdate,
+            (
+                move_confetti_system
+                    .run_if(in_state(GameStates::Playing).or(in_state(GameStates::Resetting))),
+                despawn_confetti_system,
+            ),
+        );
+}
+
+#[derive(AssetCollection, Resource)]
+pub struct EmitterAssets {
+    #[asset(key = "particles.burst")]
+    pub burst: Handle<Emitter>,
+    #[asset(key = "particles.fountain")]
+    pub fountain: Handle<Emitter>,
+    #[asset(key = "particles.drift")]
+    pub drift: Handle<Emitter>,
+    #[asset(key = "particles.sparkle")]
+    pub sparkle: Handle<Emitter>,
+}
+
+impl EmitterAssets {
+    fn get(&self, preset: EmitterPreset) -> &Handle<Emitter> {
+        match preset {
+            EmitterPreset::Burst => &self.burst,
+            EmitterPreset::Fountain => &self.fountain,
+            EmitterPreset::Drift => &self.drift,
+            EmitterPreset::Sparkle => &self.sparkle,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmitterPreset {
+    // Ring of confetti thrown outwards, used for guessed words.
+    Burst,
+    // Spray thrown upwards that falls back down.
+    Fountain,
+    // Slow confetti that floats away upwards.
+    Drift,
+    // Short-lived twinkles scattered around a point.
+    Sparkle,
+}
+
+// How a preset emits confetti. Distances are in world units, and times in seconds.
+#[derive(Debug, Deserialize, Asset, TypePath, Clone)]
+pub struct Emitter {
+    pub amount: usize,
+    pub shape: EmitterShape,
+    // Range of starting speeds.
+    pub speed: (f32, f32),
+    // Downwards acceleration, which can be negative to float upwards.
+    pub gravity: f32,
+    // Fraction of velocity lost every second.
+    pub drag: f32,
+    // Range of lifetimes.
+    pub lifetime: (f32, f32),
+    // Gradients over each particle's lifetime, with stops spaced evenly from start to end.
+    pub colors: Vec<GradientColor>,
+    pub characters: Vec<GradientCharacter>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub enum EmitterShape {
+    // Evenly spaced directions around a circle, scattered a little in depth.
+    Ring { radius: f32, depth: f32 },
+    // Random directions within `spread` radians either side of straight up.
+    Cone { spread: f32 },
+    // Random positions within a box around the origin, moving in random directions.
+    Scatter { size: (f32, f32, f32) },
+}
+
+impl EmitterShape {
+    // Starting offset from the origin and direction of travel for one particle.
+    fn sample(&self, index: usize, amount: usize, rng: &mut impl Rng) -> (Vec3, Vec3) {
+        match *self {
+            EmitterShape::Ring { radius, depth } => {
+                let theta = TAU / amount as f32 * index as f32;
+                let direction = Vec3::new(theta.cos(), theta.sin(), 0.);
+                let depth_offset = Vec3::Z * rng.gen_range(-0.5..=0.5) * depth;
+                (direction * radius + depth_offset, direction)
+            }
+            EmitterShape::Cone { spread } => {
+                let angle = rng.gen_range(-spread..=spread);
+                (Vec3::ZERO, Vec3::new(angle.sin(), angle.cos(), 0.))
+            }
+            EmitterShape::Scatter {
+                size: (width, height, depth),
+            } => {
+                let offset = Vec3::new(
+                    rng.gen_range(-0.5..=0.5) * width,
+                    rng.gen_range(-0.5..=0.5) * height,
+                    rng.gen_range(-0.5..=0.5) * depth,
+                );
+                let theta = rng.gen_range(0.0..TAU);
+                (offset, Vec3::new(theta.cos(), theta.sin(), 0.))
+            }
+        }
+    }
+}
+
+// Gradient stops, where `Spawn` stands for the color or character the confetti was spawned with.
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub enum GradientColor {
+    Spawn,
+    Rgb(u8, u8, u8),
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub enum GradientCharacter {
+    Spawn,
+    Char(char),
+}
+
+// Where confetti is emitted from.
+#[derive(Debug, Clone, Copy)]
+pub enum ParticleOrigin {
+    // A point in the scene, drawn through the camera.
+    World(Vec3),
+    // A cell of the letter widget, counted from its top left corner.
+    Letter(IVec2),
 }
 
 #[derive(Component, Debug)]
 pub struct Confetti {
-    pub color: ratatui::style::Color,
+    pub color: Color,
     pub character: char,
+    pub origin: ParticleOrigin,
+    // Offset from the origin, after the last simulation step and the one before it.
     pub position: Vec3,
+    previous_position: Vec3,
     pub velocity: Vec3,
     pub timer: Timer,
+    gravity: f32,
+    drag: f32,
+    colors: Vec<GradientColor>,
+    characters: Vec<GradientCharacter>,
+}
+
+impl Confetti {
+    pub fn current_color(&self) -> Color {
+        let stop = |gradient_color: &GradientColor| match *gradient_color {
+            GradientColor::Spawn => self.color,
+            GradientColor::Rgb(red, green, blue) => Color::Rgb(red, green, blue),
+        };
+
+        match self.colors.as_slice() {
+            [] => self.color,
+            [only] => stop(only),
+            colors => {
+                let scaled = self.timer.fraction() * (colors.len() - 1) as f32;
+                let index = (scaled as usize).min(colors.len() - 2);
+                lerp_color(
+                    stop(&colors[index]),
+                    stop(&colors[index + 1]),
+                    scaled - index as f32,
+                )
+            }
+        }
+    }
+
+    pub fn current_character(&self) -> char {
+        if self.characters.is_empty() {
+            return self.character;
+        }
+
+        let index = ((self.timer.fraction() * self.characters.len() as f32) as usize)
+            .min(self.characters.len() - 1);
+        match self.characters[index] {
+            GradientCharacter::Spawn => self.character,
+            GradientCharacter::Char(character) => character,
+        }
+    }
+
+    // Offset from the origin, blended between simulation steps by `alpha`.
+    pub fn interpolated_position(&self, alpha: f32) -> Vec3 {
+        self.previous_position.lerp(self.position, alpha)
+    }
+
+    // Cell within the letter widget, if this confetti was emitted over it.
+    pub fn letter_cell(&self, alpha: f32) -> Option<IVec2> {
+        let ParticleOrigin::Letter(origin) = self.origin else {
+            return None;
+        };
+
+        // Cells are about twice as tall as they are wide.
+        let position = self.interpolated_position(alpha);
+        let offset = Vec2::new(position.x, -position.y / 2.) * LETTER_CONFETTI_SCALE;
+        Some(origin + offset.round().as_ivec2())
+    }
 }
 
 #[derive(Debug)]
@@ -45,12 +230,16 @@ impl<'a> ConfettiWidget<'a> {
 
 impl WidgetRef for ConfettiWidget<'_> {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        if self.cell.x < 0 || self.cell.y < 0 {
+            return;
+        }
+
         let position = Position::from((self.cell.x as u16, self.cell.y as u16));
         if area.contains(position) {
             buf.cell_mut(position)
                 .unwrap()
-                .set_char(self.confetti.character)
-                .set_fg(self.confetti.color)
+                .set_char(self.confetti.current_character())
+                .set_fg(self.confetti.current_color())
                 .set_style(Style::default().bold());
         }
     }
@@ -58,45 +247,76 @@ impl WidgetRef for ConfettiWidget<'_> {
 
 #[derive(Event, Debug)]
 pub struct ConfettiSpawn {
-    pub position: Vec3,
+    pub preset: EmitterPreset,
+    pub origin: ParticleOrigin,
     pub character: char,
-    pub color: ratatui::style::Color,
+    pub color: Color,
 }
 
 fn confetti_spawn_observer(
     trigger: Trigger<ConfettiSpawn>,
     mut commands: Commands,
+    emitter_assets: Res<EmitterAssets>,
+    emitters: Res<Assets<Emitter>>,
     mut rng: Local<RngResource>,
 ) {
     let confetti_spawn = trigger.event();
+    let Some(emitter) = emitters.get(emitter_assets.get(confetti_spawn.preset)) else {
+        warn!("emitter preset {:?} is not loaded", confetti_spawn.preset);
+        return;
+    };
 
-    for index in 0..CONFETTI_AMOUNT {
-        let theta = (2.0 * PI / CONFETTI_AMOUNT as f32) * index as f32;
-        let x = confetti_spawn.position.x + theta.cos() * 0.02;
-        let y = confetti_spawn.position.y + theta.sin() * 0.02;
-        let z = confetti_spawn.position.z
-            + (rng.next_u32() as f64 / u32::MAX as f64 - 0.5) as f32 * 0.3;
-
-        let position = Vec3::new(x, y, z);
-        let velocity = (position - confetti_spawn.position).normalize() * 0.01;
+    for index in 0..emitter.amount {
+        let (position, direction) = emitter.shape.sample(index, emitter.amount, &mut rng.0);
+        let speed = rng.0.gen_range(emitter.speed.0..=emitter.speed.1);
+        let lifetime = rng.0.gen_range(emitter.lifetime.0..=emitter.lifetime.1);
 
         commands.spawn(Confetti {
-            position,
-            velocity,
-            character: confetti_spawn.character,
             color: confetti_spawn.color,
-            timer: Timer::from_seconds(2.0, TimerMode::Once),
+            character: confetti_spawn.character,
+            origin: confetti_spawn.origin,
+            position,
+            previous_position: position,
+            velocity: direction * speed,
+            timer: Timer::from_seconds(lifetime, TimerMode::Once),
+            gravity: emitter.gravity,
+            drag: emitter.drag,
+            colors: emitter.colors.clone(),
+            characters: emitter.characters.clone(),
         });
     }
 }
 
-fn move_confetti_system(mut confettis: Query<&mut Confetti>) {
+// Sprays confetti up from the bottom of the letter once it has been cleared.
+fn letter_cleared_confetti_observer(
+    _trigger: Trigger<LetterCleared>,
+    mut commands: Commands,
+    hitboxes: Res<Hitboxes>,
+) {
+    let area = hitboxes.letter_area;
+    if area.is_empty() {
+        return;
+    }
+
+    commands.trigger(ConfettiSpawn {
+        preset: EmitterPreset::Fountain,
+        origin: ParticleOrigin::Letter(IVec2::new(area.width as i32 / 2, area.height as i32 - 2)),
+        character: '*',
+        color: MAC_YELLOW_COLOR,
+    });
+}
+
+fn move_confetti_system(time: Res<Time>, mut confettis: Query<&mut Confetti>) {
+    let delta = time.delta_secs();
+
     for mut confetti in &mut confettis {
+        let drag = (1. - confetti.drag * delta).max(0.);
+        confetti.velocity.y -= confetti.gravity * delta;
+        confetti.velocity *= drag;
+
         let confetti_velocity = confetti.velocity;
-        confetti.position += confetti_velocity;
-        confetti.velocity.y -= 0.0004;
-        confetti.velocity.x -= confetti.velocity.x * 0.0001;
-        confetti.velocity.z -= confetti.velocity.z * 0.0001;
+        confetti.previous_position = confetti.position;
+        confetti.position += confetti_velocity * delta;
     }
 }
 
@@ -113,3 +333,91 @@ fn despawn_confetti_system(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use super::*;
+
+    // Confetti spawned as an orange `*`, part way through a one second lifetime.
+    fn confetti(
+        colors: Vec<GradientColor>,
+        characters: Vec<GradientCharacter>,
+        fraction: f32,
+    ) -> Confetti {
+        let mut timer = Timer::from_seconds(1., TimerMode::Once);
+        timer.tick(Duration::from_secs_f32(fraction));
+
+        Confetti {
+            color: Color::Rgb(200, 100, 0),
+            character: '*',
+            origin: ParticleOrigin::World(Vec3::ZERO),
+            position: Vec3::ZERO,
+            previous_position: Vec3::ZERO,
+            velocity: Vec3::ZERO,
+            timer,
+            gravity: 0.,
+            drag: 0.,
+            colors,
+            characters,
+        }
+    }
+
+    #[test]
+    fn color_fades_between_gradient_stops() {
+        let colors = vec![GradientColor::Spawn, GradientColor::Rgb(0, 0, 0)];
+
+        assert_eq!(
+            confetti(colors.clone(), vec![], 0.).current_color(),
+            Color::Rgb(200, 100, 0)
+        );
+        assert_eq!(
+            confetti(colors.clone(), vec![], 0.5).current_color(),
+            Color::Rgb(100, 50, 0)
+        );
+        assert_eq!(
+            confetti(colors, vec![], 1.).current_color(),
+            Color::Rgb(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn empty_gradients_keep_the_spawned_look() {
+        let confetti = confetti(vec![], vec![], 0.5);
+
+        assert_eq!(confetti.current_color(), Color::Rgb(200, 100, 0));
+        assert_eq!(confetti.current_character(), '*');
+    }
+
+    #[test]
+    fn characters_step_through_the_gradient() {
+        let characters = vec![GradientCharacter::Spawn, GradientCharacter::Char('.')];
+
+        assert_eq!(
+            confetti(vec![], characters.clone(), 0.25).current_character(),
+            '*'
+        );
+        assert_eq!(
+            confetti(vec![], characters.clone(), 0.75).current_character(),
+            '.'
+        );
+        assert_eq!(confetti(vec![], characters, 1.).current_character(), '.');
+    }
+
+    #[test]
+    fn ring_spaces_directions_evenly() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        let ring = EmitterShape::Ring {
+            radius: 2.,
+            depth: 0.,
+        };
+
+        let (offset, direction) = ring.sample(1, 4, &mut rng);
+        assert!(direction.abs_diff_eq(Vec3::Y, 1e-6));
+        assert!(offset.abs_diff_eq(Vec3::Y * 2., 1e-6));
+    }
+}
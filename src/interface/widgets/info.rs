@@ -0,0 +1,107 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Stylize,
+    text::{Line, Text},
+    widgets::{Block, BorderType, Padding, Paragraph, Widget, Wrap},
+};
+
+use crate::{
+    constants::{MAC_PURPLE_COLOR, MAC_RED_COLOR, PLASTIC_MEDIUM_BACKGROUND_COLOR},
+    input::{GameAction, keymap::Keymap},
+    settings::Settings,
+};
+
+#[derive(Debug)]
+pub struct InfoWidget<'a> {
+    pub keymap: &'a Keymap,
+    pub settings: &'a Settings,
+}
+
+impl InfoWidget<'_> {
+    fn controls_line(&self, text: &str, actions: &[GameAction]) -> Line<'static> {
+        let keys: Vec<_> = actions
+            .iter()
+            .map(|action| self.keymap.describe(*action))
+            .collect();
+
+        Line::from(format!("Press {} to {text}.", keys.join(" / "))).fg(MAC_RED_COLOR)
+    }
+}
+
+impl Widget for InfoWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let outer_block = Block::default().padding(Padding::proportional(2));
+        let info_block = Block::bordered()
+            .border_type(BorderType::Double)
+            .padding(Padding::proportional(2))
+            .bg(PLASTIC_MEDIUM_BACKGROUND_COLOR);
+
+        let info_paragraph = Paragraph::new(Text::from(vec![
+            Line::from("HOW TO PLAY").bold().fg(MAC_PURPLE_COLOR),
+            Line::from(""),
+            Line::from(
+                "Each round, a chain letter will appear on the left. Each letter has a list of \
+                blessings, for if the letter is forwarded, and a list of curses, for if the \
+                chain is broken. Each blessing and curse has a missing word. Your job is to \
+                look at the pool of words moving past on the right side of the screen, and \
+                figure out which ones correspond with the blessings.",
+            ),
+            Line::from(""),
+            Line::from(
+                "Type your word and press enter. Matching blessings bestow money or score, \
+                curses take it away, and decoys do nothing (currently). Collect all the \
+                blessings to collect your income and move to the next round.",
+            ),
+            Line::from(""),
+            Line::from("Someday the money will do something. Today is not that day."),
+            Line::from(""),
+            self.controls_line("submit", &[GameAction::Submit]),
+            self.controls_line("erase", &[GameAction::Backspace]),
+            self.controls_line(
+                "recall earlier guesses",
+                &[GameAction::HistoryPrevious, GameAction::HistoryNext],
+            ),
+            self.controls_line(
+                "scroll the letter",
+                &[GameAction::ScrollUp, GameAction::ScrollDown],
+            ),
+            self.controls_line("pause", &[GameAction::Pause]),
+            self.controls_line("toggle sound", &[GameAction::ToggleSound]),
+            self.controls_line("show typing stats", &[GameAction::ToggleStats]),
+            self.controls_line(
+                "export the last cleared letter",
+                &[GameAction::ExportLetter],
+            ),
+            self.controls_line("take a screenshot", &[GameAction::Screenshot]),
+            Line::from(
+                "On a gamepad, pick letters with the left stick, A to type, B to erase, X to \
+                submit, the D-pad to scroll and START to pause.",
+            )
+            .fg(MAC_RED_COLOR),
+            Line::from(""),
+            Line::from(format!(
+                "DIFFICULTY: {} (press {} to change)",
+                self.settings.difficulty,
+                self.keymap.describe(GameAction::CycleDifficulty)
+            ))
+            .fg(MAC_PURPLE_COLOR),
+            Line::from(""),
+            Line::from(format!(
+                "PRESS {} TO BEGIN",
+                self.keymap.describe(GameAction::Confirm)
+            ))
+            .bold()
+            .fg(MAC_PURPLE_COLOR),
+        ]))
+        .wrap(Wrap { trim: true });
+
+        let inner_area = outer_block.inner(area);
+        let inner_inner_area = info_block.inner(inner_area);
+        info_block.render(inner_area, buf);
+        info_paragraph.render(inner_inner_area, buf);
+    }
+}
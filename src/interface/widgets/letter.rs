@@ -1,7 +1,7 @@
 use std::time::Duration;
 
 use bevy::prelude::*;
-use ratatui::layout::{Alignment, Size};
+use ratatui::layout::{Alignment, Position, Size};
 use ratatui::style::Stylize;
 use ratatui::widgets::{Block, Padding, Wrap};
 use ratatui::{
@@ -45,6 +45,10 @@ pub struct LetterWidget<'a>(pub &'a Letter);
 pub struct LetterWidgetState {
     pub effect: Effect,
     pub scroll_state: ScrollViewState,
+    // Cell under the mouse, used to highlight the testimonial being hovered.
+    pub pointer: Option<Position>,
+    // Testimonial lines visible on the last frame, with their words.
+    pub testimonial_areas: Vec<(Rect, String)>,
     revealed: LetterWidgetRevealed,
 }
 
@@ -59,11 +63,29 @@ impl Default for LetterWidgetState {
                 (1000, Interpolation::Linear),
             ),
             scroll_state: ScrollViewState::default(),
+            pointer: None,
+            testimonial_areas: vec![],
             revealed: LetterWidgetRevealed::default(),
         }
     }
 }
 
+impl LetterWidgetState {
+    // State with the whole letter already printed, for previews outside of the game loop.
+    pub fn revealed(letter: &Letter) -> Self {
+        let mut state = Self::default();
+        let skip = Duration::from_secs(3600);
+
+        state.revealed = LetterWidgetRevealed::default().next_state(skip, letter);
+        state
+            .effect
+            .timer_mut()
+            .and_then(|t| t.process(skip.into()));
+
+        state
+    }
+}
+
 #[derive(Default)]
 struct LetterWidgetRevealed {
     elapsed_ms: u32,
@@ -160,6 +182,10 @@ impl StatefulWidget for &LetterWidget<'_> {
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         let mut lines: Vec<Line> = Vec::new();
+        // Indices of lines holding testimonials that are still missing their word.
+        let mut blank_lines = vec![];
+        // Indices of every testimonial line, with the word it is missing.
+        let mut testimonial_lines = vec![];
         let LetterWidgetRevealed {
             title_revealed,
             body_chars_revealed,
@@ -201,21 +227,25 @@ impl StatefulWidget for &LetterWidget<'_> {
             let blessing = &self.blessings[i];
 
             let message = if blessing.collected {
-                Span::from(blessing.message.replace(
-                    &"_".repeat(blessing.target_word.len()),
-                    &blessing.target_word,
-                ))
-                .fg(MAC_GREEN_MUTED_COLOR)
+                Span::from(blessing.revealed_message()).fg(MAC_GREEN_MUTED_COLOR)
+            } else if blessing.lost {
+                Span::from(&blessing.message)
+                    .fg(PLASTIC_SECONDARY_COLOR)
+                    .crossed_out()
             } else {
+                blank_lines.push(lines.len());
                 Span::from(&blessing.message)
             };
 
             let suffix = if blessing.collected {
                 Span::from(" ".to_string() + &blessing.effect.to_string()).fg(MAC_GREEN_COLOR)
+            } else if blessing.lost {
+                Span::from(" LOST").fg(MAC_RED_COLOR)
             } else {
                 Span::from("")
             };
 
+            testimonial_lines.push((lines.len(), blessing.target_word.clone()));
             lines.push(Line::from(vec![
                 Span::from("+ ").fg(MAC_GREEN_COLOR).bold(),
                 message,
@@ -234,13 +264,9 @@ impl StatefulWidget for &LetterWidget<'_> {
             let curse = &self.curses[i];
 
             let message = if curse.collected {
-                Span::from(
-                    curse
-                        .message
-                        .replace(&"_".repeat(curse.target_word.len()), &curse.target_word),
-                )
-                .fg(MAC_RED_MUTED_COLOR)
+                Span::from(curse.revealed_message()).fg(MAC_RED_MUTED_COLOR)
             } else {
+                blank_lines.push(lines.len());
                 Span::from(&curse.message)
             };
 
@@ -250,6 +276,7 @@ impl StatefulWidget for &LetterWidget<'_> {
                 Span::from("")
             };
 
+            testimonial_lines.push((lines.len(), curse.target_word.clone()));
             lines.push(Line::from(vec![
                 Span::from("- ").fg(MAC_RED_COLOR).bold(),
                 message,
@@ -274,11 +301,6 @@ impl StatefulWidget for &LetterWidget<'_> {
             lines.push(Line::from(self.flavor.footer.clone()).fg(PLASTIC_SECONDARY_COLOR));
         }
 
-        // wrap in paragraph
-        let paragraph = Paragraph::new(Text::from(lines))
-            .wrap(Wrap { trim: true })
-            .fg(PLASTIC_PRIMARY_COLOR);
-
         // pad window
         let area = Block::default()
             .padding(Padding::proportional(LETTER_PADDING))
@@ -298,6 +320,50 @@ impl StatefulWidget for &LetterWidget<'_> {
             .padding(Padding::proportional(1))
             .inner(unpadded_block_inner_area);
 
+        // highlight hovered testimonial
+        if let Some(pointer) = state
+            .pointer
+            .filter(|pointer| block_inner_area.contains(*pointer))
+        {
+            let row = pointer.y - block_inner_area.y + state.scroll_state.offset().y;
+            let width = block_inner_area.width;
+
+            for index in blank_lines {
+                let start = wrapped_height(&lines[..index], width);
+                let end = start + wrapped_height(&lines[index..=index], width);
+
+                if (start..end).contains(&row) {
+                    lines[index] = lines[index].clone().bg(PLASTIC_LIGHT_BACKGROUND_COLOR);
+                    break;
+                }
+            }
+        }
+
+        // record where testimonials are, for effects drawn over them
+        state.testimonial_areas.clear();
+        for (index, word) in testimonial_lines {
+            let width = block_inner_area.width;
+            let start = wrapped_height(&lines[..index], width) as i32
+                - state.scroll_state.offset().y as i32;
+            let height = wrapped_height(&lines[index..=index], width);
+
+            if (0..block_inner_area.height as i32).contains(&start) {
+                let area = Rect::new(
+                    block_inner_area.x,
+                    block_inner_area.y + start as u16,
+                    width,
+                    height,
+                )
+                .intersection(block_inner_area);
+                state.testimonial_areas.push((area, word));
+            }
+        }
+
+        // wrap in paragraph
+        let paragraph = Paragraph::new(Text::from(lines))
+            .wrap(Wrap { trim: true })
+            .fg(PLASTIC_PRIMARY_COLOR);
+
         let paragraph_height = paragraph.line_count(block_inner_area.width);
 
         let scroll_buffer_size = Size::new(
@@ -335,6 +401,13 @@ impl StatefulWidget for &LetterWidget<'_> {
     }
 }
 
+// Number of rows the lines take up once wrapped, matching how the letter paragraph wraps.
+fn wrapped_height(lines: &[Line], width: u16) -> u16 {
+    Paragraph::new(Text::from(lines.to_vec()))
+        .wrap(Wrap { trim: true })
+        .line_count(width) as u16
+}
+
 pub fn letter_reveal_system(
     mut commands: Commands,
     current_letter: Option<Res<CurrentLetter>>,
@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Widget},
+};
+
+use crate::{
+    constants::{
+        MAC_YELLOW_COLOR, PLASTIC_EMPHASIS_COLOR, PLASTIC_MEDIUM_BACKGROUND_COLOR,
+        PLASTIC_PRIMARY_COLOR,
+    },
+    input::gamepad::{LETTER_PICKER_COLUMNS, LETTER_PICKER_LETTERS, LetterPicker},
+};
+
+#[derive(Deref, DerefMut, Debug)]
+pub struct LetterPickerWidget<'a>(pub &'a LetterPicker);
+
+impl Widget for LetterPickerWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let rows = LETTER_PICKER_LETTERS.chunks(LETTER_PICKER_COLUMNS);
+        let width = LETTER_PICKER_COLUMNS as u16 * 3 + 2;
+        let height = rows.len() as u16 + 2;
+
+        // Sits centered along the bottom of the area.
+        let [_, picker_area] =
+            *Layout::vertical([Constraint::Fill(1), Constraint::Length(height)]).split(area)
+        else {
+            unreachable!()
+        };
+        let [_, picker_area, _] = *Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Length(width),
+            Constraint::Fill(1),
+        ])
+        .split(picker_area) else {
+            unreachable!()
+        };
+
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .title_bottom(Line::from(" A TYPE  B ERASE  X SEND ").centered())
+            .fg(PLASTIC_PRIMARY_COLOR)
+            .bg(PLASTIC_MEDIUM_BACKGROUND_COLOR);
+        let inner_area = block.inner(picker_area);
+        block.render(picker_area, buf);
+
+        for (row_index, row) in rows.enumerate() {
+            let spans: Vec<_> = row
+                .iter()
+                .enumerate()
+                .map(|(column_index, letter)| {
+                    let index = row_index * LETTER_PICKER_COLUMNS + column_index;
+                    let span = Span::from(format!(" {letter} "));
+
+                    if index == self.selected {
+                        span.fg(PLASTIC_MEDIUM_BACKGROUND_COLOR)
+                            .bg(MAC_YELLOW_COLOR)
+                            .bold()
+                    } else {
+                        span.fg(PLASTIC_EMPHASIS_COLOR)
+                    }
+                })
+                .collect();
+
+            let row_area = Rect {
+                y: inner_area.y + row_index as u16,
+                height: 1,
+                ..inner_area
+            };
+            Line::from(spans).render(row_area.intersection(inner_area), buf);
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Padding, Paragraph, Widget},
+};
+
+use crate::{
+    analytics::AnalyticsSummary,
+    constants::{
+        MAC_PURPLE_COLOR, PLASTIC_EMPHASIS_COLOR, PLASTIC_MEDIUM_BACKGROUND_COLOR,
+        PLASTIC_PRIMARY_COLOR, PLASTIC_SECONDARY_COLOR,
+    },
+    metrics::TypingMetrics,
+};
+
+// Label and value for each number worth showing about some span of typing.
+fn metric_rows(metrics: &TypingMetrics) -> Vec<(&'static str, String)> {
+    let reaction_time = metrics
+        .average_reaction_time()
+        .map(|reaction_time| format!("{:.1}s", reaction_time.as_secs_f32()))
+        .unwrap_or_else(|| "-".into());
+
+    vec![
+        ("WPM", format!("{:.0}", metrics.words_per_minute())),
+        (
+            "KEYSTROKE ACCURACY",
+            format!("{:.0}%", metrics.keystroke_accuracy() * 100.),
+        ),
+        (
+            "GUESS ACCURACY",
+            format!("{:.0}%", metrics.guess_accuracy() * 100.),
+        ),
+        ("TIME TO GUESS", reaction_time),
+        ("CHARACTERS TYPED", metrics.characters_typed.to_string()),
+        ("CORRECTIONS", metrics.corrections.to_string()),
+        ("MISSED BLESSINGS", metrics.missed_blessings.to_string()),
+        (
+            "GUESSES",
+            format!("{}/{}", metrics.correct_guesses, metrics.guesses),
+        ),
+    ]
+}
+
+fn metric_line(label: &str, value: String, width: u16) -> Line<'static> {
+    let padding = (width as usize).saturating_sub(label.len() + value.len());
+
+    Line::from(vec![
+        Span::from(label.to_string()).fg(PLASTIC_SECONDARY_COLOR),
+        Span::from(" ".repeat(padding)),
+        Span::from(value).fg(PLASTIC_EMPHASIS_COLOR).bold(),
+    ])
+}
+
+fn centered(area: Rect, width: u16, height: u16) -> Rect {
+    let [_, area, _] = *Layout::vertical([
+        Constraint::Fill(1),
+        Constraint::Length(height),
+        Constraint::Fill(1),
+    ])
+    .split(area) else {
+        unreachable!()
+    };
+    let [_, area, _] = *Layout::horizontal([
+        Constraint::Fill(1),
+        Constraint::Length(width),
+        Constraint::Fill(1),
+    ])
+    .split(area) else {
+        unreachable!()
+    };
+
+    area
+}
+
+// Numbers for the letter that just ended, shown over the scene between letters.
+pub struct TallyWidget<'a> {
+    pub metrics: &'a TypingMetrics,
+    // Keys that skip the tally, as described by the keymap.
+    pub continue_keys: String,
+}
+
+impl Widget for TallyWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let rows = metric_rows(self.metrics);
+        let tally_area = centered(area, 34, rows.len() as u16 + 4);
+
+        let block = Block::bordered()
+            .border_type(BorderType::Double)
+            .padding(Padding::horizontal(1))
+            .title(Line::from(" TALLY ").bold().fg(MAC_PURPLE_COLOR).centered())
+            .fg(PLASTIC_PRIMARY_COLOR)
+            .bg(PLASTIC_MEDIUM_BACKGROUND_COLOR);
+        let inner_area = block.inner(tally_area);
+
+        let mut lines: Vec<_> = rows
+            .into_iter()
+            .map(|(label, value)| metric_line(label, value, inner_area.width))
+            .collect();
+        lines.push(Line::from(""));
+        lines.push(
+            Line::from(format!("{} TO CONTINUE", self.continue_keys))
+                .fg(PLASTIC_SECONDARY_COLOR)
+                .centered(),
+        );
+
+        block.render(tally_area, buf);
+        Paragraph::new(lines).render(inner_area, buf);
+    }
+}
+
+// Target words with how often they were guessed, as a percentage of times shown.
+fn word_rows(words: &[(String, usize, usize)]) -> Vec<(String, String)> {
+    if words.is_empty() {
+        return vec![("-".into(), String::new())];
+    }
+
+    words
+        .iter()
+        .map(|(word, guessed, shown)| {
+            (
+                word.to_uppercase(),
+                format!("{:.0}% of {shown}", *guessed as f32 / *shown as f32 * 100.),
+            )
+        })
+        .collect()
+}
+
+// Run and lifetime numbers side by side, with letter analytics below, toggled over the whole screen.
+pub struct TypingStatsWidget<'a> {
+    pub run: &'a TypingMetrics,
+    pub lifetime: &'a TypingMetrics,
+    pub analytics: &'a AnalyticsSummary,
+}
+
+impl Widget for TypingStatsWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let hardest_rows = word_rows(&self.analytics.hardest_words);
+        let easiest_rows = word_rows(&self.analytics.easiest_words);
+        let metric_height = metric_rows(self.run).len() as u16 + 2;
+        let analytics_height = hardest_rows.len().max(easiest_rows.len()) as u16 + 5;
+        let stats_area = centered(
+            area,
+            72.min(area.width),
+            metric_height + analytics_height + 2,
+        );
+
+        let block = Block::bordered()
+            .border_type(BorderType::Double)
+            .padding(Padding::horizontal(1))
+            .title(
+                Line::from(" TYPING STATS ")
+                    .bold()
+                    .fg(MAC_PURPLE_COLOR)
+                    .centered(),
+            )
+            .fg(PLASTIC_PRIMARY_COLOR)
+            .bg(PLASTIC_MEDIUM_BACKGROUND_COLOR);
+        let inner_area = block.inner(stats_area);
+        block.render(stats_area, buf);
+
+        let [metrics_area, analytics_area] =
+            *Layout::vertical([Constraint::Length(metric_height), Constraint::Fill(1)])
+                .split(inner_area)
+        else {
+            unreachable!()
+        };
+
+        let columns = Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Length(2),
+            Constraint::Fill(1),
+        ]);
+        let [run_area, _, lifetime_area] = *columns.split(metrics_area) else {
+            unreachable!()
+        };
+
+        for (title, metrics, column_area) in [
+            ("THIS RUN", self.run, run_area),
+            ("LIFETIME", self.lifetime, lifetime_area),
+        ] {
+            let mut lines = vec![
+                metric_line(
+                    title,
+                    format!("{} letters", metrics.letters),
+                    column_area.width,
+                )
+                .bold(),
+                Line::from(""),
+            ];
+            lines.extend(
+                metric_rows(metrics)
+                    .into_iter()
+                    .map(|(label, value)| metric_line(label, value, column_area.width)),
+            );
+
+            Paragraph::new(lines).render(column_area, buf);
+        }
+
+        let [letters_area, words_area] =
+            *Layout::vertical([Constraint::Length(3), Constraint::Fill(1)]).split(analytics_area)
+        else {
+            unreachable!()
+        };
+        let [played_area, _, time_area] = *columns.split(letters_area) else {
+            unreachable!()
+        };
+        let [hardest_area, _, easiest_area] = *columns.split(words_area) else {
+            unreachable!()
+        };
+
+        let summary = self.analytics;
+        Paragraph::new(vec![
+            metric_line(
+                "LETTERS PLAYED",
+                summary.letters_played.to_string(),
+                played_area.width,
+            ),
+            metric_line(
+                "LETTERS CLEARED",
+                summary.letters_cleared.to_string(),
+                played_area.width,
+            ),
+        ])
+        .render(played_area, buf);
+        Paragraph::new(vec![metric_line(
+            "AVERAGE TIME",
+            format!("{:.0}s", summary.average_seconds),
+            time_area.width,
+        )])
+        .render(time_area, buf);
+
+        for (title, rows, column_area) in [
+            ("HARDEST WORDS", hardest_rows, hardest_area),
+            ("EASIEST WORDS", easiest_rows, easiest_area),
+        ] {
+            let mut lines = vec![Line::from(title).bold().fg(PLASTIC_SECONDARY_COLOR)];
+            lines.extend(
+                rows.into_iter()
+                    .map(|(word, value)| metric_line(&word, value, column_area.width)),
+            );
+
+            Paragraph::new(lines).render(column_area, buf);
+        }
+    }
+}
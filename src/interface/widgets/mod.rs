@@ -1,9 +1,14 @@
 use bevy::prelude::*;
 
 pub mod confetti;
+pub mod info;
 pub mod letter;
+pub mod letter_picker;
+pub mod metrics;
+pub mod paused;
 pub mod prompt;
 pub mod statistics;
+pub mod toast;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_plugins((letter::plugin, prompt::plugin, confetti::plugin));
@@ -0,0 +1,41 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::Stylize,
+    text::Line,
+    widgets::{Block, Paragraph, Widget},
+};
+
+use crate::constants::{MAC_PURPLE_COLOR, PLASTIC_MEDIUM_BACKGROUND_COLOR};
+
+// Small box in the middle of the area, shown over the scene while the game is paused.
+pub struct PausedWidget;
+
+impl Widget for PausedWidget {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let [_, row_area, _] = *Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(3),
+            Constraint::Fill(1),
+        ])
+        .split(area) else {
+            unreachable!()
+        };
+        let [_, paused_area, _] = *Layout::horizontal([
+            Constraint::Fill(1),
+            Constraint::Length(12),
+            Constraint::Fill(1),
+        ])
+        .split(row_area) else {
+            unreachable!()
+        };
+
+        Paragraph::new(Line::from("PAUSED").bold().fg(MAC_PURPLE_COLOR))
+            .centered()
+            .block(Block::bordered().bg(PLASTIC_MEDIUM_BACKGROUND_COLOR))
+            .render(paused_area, buf);
+    }
+}
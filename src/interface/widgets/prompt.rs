@@ -14,7 +14,9 @@ use crate::{
         CURSOR_BLINK_SPEED, CUSTOM_BORDERS_UNDER, MAC_RED_MUTED_COLOR, PLASTIC_EMPHASIS_COLOR,
         PLASTIC_MEDIUM_BACKGROUND_COLOR, PLASTIC_PRIMARY_COLOR, PLASTIC_SECONDARY_COLOR,
     },
+    interface::hitboxes::Hitboxes,
     letters::CurrentLetter,
+    settings::Settings,
     states::{GameStates, LetterFailed, generate_current_letter_system},
 };
 
@@ -29,6 +31,7 @@ pub(super) fn plugin(app: &mut App) {
             Update,
             (
                 tick_timer_system,
+                update_completion_system,
                 blink_prompt_system.run_if(on_timer(Duration::from_millis(CURSOR_BLINK_SPEED))),
             )
                 .run_if(in_state(GameStates::Playing)),
@@ -38,7 +41,169 @@ pub(super) fn plugin(app: &mut App) {
 #[derive(Resource, Default)]
 pub struct Prompt {
     pub text: String,
+    // Position of the cursor, in characters from the start of the text.
+    pub cursor: usize,
+    // Rest of a visible word that starts with the text, shown as ghost text when assists allow.
+    pub completion: String,
     pub timer: Timer,
+    // Words submitted during the current letter, oldest first.
+    history: Vec<String>,
+    // Entry being recalled from the history, and the text that was typed before recalling it.
+    recalled: Option<(usize, String)>,
+}
+
+impl Prompt {
+    fn byte_index(&self, cursor: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(cursor)
+            .map_or(self.text.len(), |(index, _)| index)
+    }
+
+    fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    fn set_text(&mut self, text: String) {
+        self.text = text;
+        self.cursor = self.len();
+    }
+
+    // Swaps out whatever has been typed, e.g. for a word picked with the mouse.
+    pub fn replace(&mut self, text: String) {
+        self.set_text(text);
+        self.recalled = None;
+    }
+
+    pub fn insert(&mut self, c: char) {
+        let index = self.byte_index(self.cursor);
+        self.text.insert(index, c);
+        self.cursor += 1;
+    }
+
+    pub fn backspace(&mut self) {
+        if self.cursor > 0 {
+            self.cursor -= 1;
+            let index = self.byte_index(self.cursor);
+            self.text.remove(index);
+        }
+    }
+
+    pub fn delete(&mut self) {
+        if self.cursor < self.len() {
+            let index = self.byte_index(self.cursor);
+            self.text.remove(index);
+        }
+    }
+
+    // Deletes back to the start of the word before the cursor, like `ctrl+w` in a shell.
+    pub fn delete_word(&mut self) {
+        let before: Vec<char> = self.text.chars().take(self.cursor).collect();
+        let trailing_spaces = before
+            .iter()
+            .rev()
+            .take_while(|c| c.is_whitespace())
+            .count();
+        let word = before
+            .iter()
+            .rev()
+            .skip(trailing_spaces)
+            .take_while(|c| !c.is_whitespace())
+            .count();
+
+        let start = self.byte_index(self.cursor - trailing_spaces - word);
+        let end = self.byte_index(self.cursor);
+        self.text.replace_range(start..end, "");
+        self.cursor -= trailing_spaces + word;
+    }
+
+    // Deletes everything before the cursor, like `ctrl+u` in a shell.
+    pub fn delete_to_start(&mut self) {
+        let end = self.byte_index(self.cursor);
+        self.text.replace_range(..end, "");
+        self.cursor = 0;
+    }
+
+    pub fn at_end(&self) -> bool {
+        self.cursor == self.len()
+    }
+
+    // Fills in the ghost text, returning whether there was any.
+    pub fn accept_completion(&mut self) -> bool {
+        if self.completion.is_empty() || !self.at_end() {
+            return false;
+        }
+
+        let completion = std::mem::take(&mut self.completion);
+        self.text.push_str(&completion);
+        self.cursor = self.len();
+        true
+    }
+
+    pub fn move_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.len());
+    }
+
+    pub fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn move_end(&mut self) {
+        self.cursor = self.len();
+    }
+
+    pub fn history_previous(&mut self) {
+        let index = match &self.recalled {
+            Some((0, _)) => return,
+            Some((index, _)) => index - 1,
+            None if self.history.is_empty() => return,
+            None => self.history.len() - 1,
+        };
+
+        let typed = match self.recalled.take() {
+            Some((_, typed)) => typed,
+            None => self.text.clone(),
+        };
+
+        self.set_text(self.history[index].clone());
+        self.recalled = Some((index, typed));
+    }
+
+    pub fn history_next(&mut self) {
+        let Some((index, typed)) = self.recalled.take() else {
+            return;
+        };
+
+        if index + 1 < self.history.len() {
+            self.set_text(self.history[index + 1].clone());
+            self.recalled = Some((index + 1, typed));
+        } else {
+            self.set_text(typed);
+        }
+    }
+
+    // Empties the prompt, remembering the submitted word so it can be recalled later.
+    pub fn submit(&mut self) {
+        let text = std::mem::take(&mut self.text);
+        if !text.is_empty() && self.history.last() != Some(&text) {
+            self.history.push(text);
+        }
+
+        self.cursor = 0;
+        self.recalled = None;
+    }
+
+    // Empties the prompt and forgets the history, for when a new letter begins.
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.cursor = 0;
+        self.history.clear();
+        self.recalled = None;
+    }
 }
 
 #[derive(Resource, Default)]
@@ -73,12 +238,35 @@ impl StatefulWidget for &Prompt {
             unreachable!()
         };
 
-        let cursor = if state.cursor_visible { "_" } else { "" };
+        let before_cursor: String = self.text.chars().take(self.cursor).collect();
+        let after_cursor: String = self.text.chars().skip(self.cursor + 1).collect();
+
+        let mut completion = self.completion.chars();
+        let ghost_cursor = completion.next().filter(|_| self.at_end());
+        let ghost_text: String = completion.filter(|_| self.at_end()).collect();
+
+        // The cursor is drawn over the character it sits on, or as an underscore past the end.
+        let cursor = match self.text.chars().nth(self.cursor).or(ghost_cursor) {
+            Some(c) if ghost_cursor.is_some() && state.cursor_visible => Span::from(c.to_string())
+                .fg(PLASTIC_SECONDARY_COLOR)
+                .reversed(),
+            Some(c) if ghost_cursor.is_some() => {
+                Span::from(c.to_string()).fg(PLASTIC_SECONDARY_COLOR).dim()
+            }
+            Some(c) if state.cursor_visible => Span::from(c.to_string())
+                .fg(PLASTIC_EMPHASIS_COLOR)
+                .reversed(),
+            Some(c) => Span::from(c.to_string()).fg(PLASTIC_EMPHASIS_COLOR),
+            None if state.cursor_visible => Span::from("_").fg(PLASTIC_SECONDARY_COLOR),
+            None => Span::from(""),
+        };
 
         let text = Line::from(vec![
             Span::from(" > ").fg(PLASTIC_SECONDARY_COLOR).bold(),
-            Span::from(self.text.clone()).fg(PLASTIC_EMPHASIS_COLOR),
-            Span::from(cursor).fg(PLASTIC_SECONDARY_COLOR),
+            Span::from(before_cursor).fg(PLASTIC_EMPHASIS_COLOR),
+            cursor,
+            Span::from(after_cursor).fg(PLASTIC_EMPHASIS_COLOR),
+            Span::from(ghost_text).fg(PLASTIC_SECONDARY_COLOR).dim(),
         ]);
 
         let timer_text = Line::from(format!(" {:.1}s ", self.timer.remaining_secs())).bold();
@@ -111,3 +299,127 @@ fn tick_timer_system(mut commands: Commands, time: Res<Time>, mut prompt: ResMut
         commands.trigger(LetterFailed);
     }
 }
+
+// Offers the rest of the shortest visible word starting with what has been typed.
+fn update_completion_system(
+    mut prompt: ResMut<Prompt>,
+    hitboxes: Res<Hitboxes>,
+    settings: Res<Settings>,
+) {
+    let completion = if settings.assist().ghost_text && !prompt.text.is_empty() {
+        hitboxes
+            .word_labels
+            .iter()
+            .map(|(_, word)| word)
+            .filter(|word| word.len() > prompt.text.len() && word.starts_with(&prompt.text))
+            .min_by_key(|word| (word.len(), word.as_str()))
+            .map(|word| word[prompt.text.len()..].to_string())
+            .unwrap_or_default()
+    } else {
+        String::new()
+    };
+
+    if prompt.completion != completion {
+        prompt.completion = completion;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prompt(text: &str, cursor: usize) -> Prompt {
+        Prompt {
+            text: text.into(),
+            cursor,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn inserts_and_deletes_at_the_cursor() {
+        let mut prompt = prompt("héllo", 2);
+        prompt.insert('x');
+        assert_eq!((prompt.text.as_str(), prompt.cursor), ("héxllo", 3));
+
+        prompt.backspace();
+        prompt.backspace();
+        assert_eq!((prompt.text.as_str(), prompt.cursor), ("hllo", 1));
+
+        prompt.delete();
+        assert_eq!((prompt.text.as_str(), prompt.cursor), ("hlo", 1));
+    }
+
+    #[test]
+    fn editing_stops_at_the_ends() {
+        let mut prompt = prompt("ab", 0);
+        prompt.backspace();
+        prompt.move_left();
+        assert_eq!((prompt.text.as_str(), prompt.cursor), ("ab", 0));
+
+        prompt.move_end();
+        prompt.delete();
+        prompt.move_right();
+        assert_eq!((prompt.text.as_str(), prompt.cursor), ("ab", 2));
+    }
+
+    #[test]
+    fn delete_word_takes_trailing_spaces_with_it() {
+        let mut prompt = prompt("one two  three", 9);
+        prompt.delete_word();
+        assert_eq!((prompt.text.as_str(), prompt.cursor), ("one three", 4));
+
+        prompt.delete_word();
+        assert_eq!((prompt.text.as_str(), prompt.cursor), ("three", 0));
+    }
+
+    #[test]
+    fn delete_to_start_keeps_text_after_the_cursor() {
+        let mut prompt = prompt("forward", 4);
+        prompt.delete_to_start();
+        assert_eq!((prompt.text.as_str(), prompt.cursor), ("ard", 0));
+    }
+
+    #[test]
+    fn completion_is_only_accepted_at_the_end() {
+        let mut prompt = prompt("bless", 2);
+        prompt.completion = "ing".into();
+        assert!(!prompt.accept_completion());
+
+        prompt.move_end();
+        assert!(prompt.accept_completion());
+        assert_eq!((prompt.text.as_str(), prompt.cursor), ("blessing", 8));
+        assert!(prompt.completion.is_empty());
+    }
+
+    #[test]
+    fn history_recalls_submitted_words_and_restores_typing() {
+        let mut prompt = Prompt::default();
+        for word in ["first", "second", "second", ""] {
+            prompt.replace(word.into());
+            prompt.submit();
+        }
+        prompt.insert('x');
+
+        prompt.history_previous();
+        assert_eq!(prompt.text, "second");
+        prompt.history_previous();
+        assert_eq!(prompt.text, "first");
+        prompt.history_previous();
+        assert_eq!(prompt.text, "first");
+
+        prompt.history_next();
+        assert_eq!(prompt.text, "second");
+        prompt.history_next();
+        assert_eq!((prompt.text.as_str(), prompt.cursor), ("x", 1));
+    }
+
+    #[test]
+    fn clear_forgets_the_history() {
+        let mut prompt = prompt("word", 4);
+        prompt.submit();
+        prompt.clear();
+        prompt.history_previous();
+        assert!(prompt.text.is_empty());
+    }
+}
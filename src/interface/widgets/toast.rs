@@ -0,0 +1,62 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::Stylize,
+    text::Line,
+    widgets::{Block, BorderType, Padding, Paragraph, Widget, Wrap},
+};
+
+use crate::{
+    achievements::Achievement,
+    constants::{
+        MAC_YELLOW_COLOR, PLASTIC_MEDIUM_BACKGROUND_COLOR, PLASTIC_PRIMARY_COLOR,
+        PLASTIC_SECONDARY_COLOR,
+    },
+};
+
+// Notice in the top right corner of the area for a newly unlocked achievement.
+pub struct ToastWidget<'a>(pub &'a Achievement);
+
+impl ToastWidget<'_> {
+    // Area the toast will be drawn in, so that effects can be limited to it.
+    pub fn area(area: Rect) -> Rect {
+        let [toast_area, _] =
+            *Layout::vertical([Constraint::Length(5), Constraint::Fill(1)]).split(area)
+        else {
+            unreachable!()
+        };
+        let [_, toast_area] =
+            *Layout::horizontal([Constraint::Fill(1), Constraint::Length(36)]).split(toast_area)
+        else {
+            unreachable!()
+        };
+
+        toast_area
+    }
+}
+
+impl Widget for ToastWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let toast_area = Self::area(area);
+
+        Paragraph::new(vec![
+            Line::from(self.0.name.to_uppercase())
+                .bold()
+                .fg(MAC_YELLOW_COLOR),
+            Line::from(self.0.description.clone()).fg(PLASTIC_SECONDARY_COLOR),
+        ])
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::bordered()
+                .border_type(BorderType::Double)
+                .padding(Padding::horizontal(1))
+                .title(" ACHIEVEMENT ")
+                .fg(PLASTIC_PRIMARY_COLOR)
+                .bg(PLASTIC_MEDIUM_BACKGROUND_COLOR),
+        )
+        .render(toast_area, buf);
+    }
+}
@@ -3,10 +3,13 @@ use bevy_asset_loader::asset_collection::AssetCollection;
 use bevy_common_assets::ron::RonAssetPlugin;
 use bevy_shuffle_bag::ShuffleBag;
 use rand::{Rng, seq::IteratorRandom};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    constants::TIME_LIMIT_RANGE, rng::RngResource, scene::spawning::WordCube, states::GameStates,
+    constants::TIME_LIMIT_RANGE,
+    rng::RngResource,
+    scene::spawning::{WordCube, WordCubeKind},
+    states::GameStates,
 };
 
 pub(super) fn plugin(app: &mut App) {
@@ -17,7 +20,7 @@ pub(super) fn plugin(app: &mut App) {
     ]))
     .add_plugins(RonAssetPlugin::<Flavor>::new(&["flavor.ron"]))
     .add_plugins(RonAssetPlugin::<Name>::new(&["name.ron"]))
-    .add_systems(OnExit(GameStates::Loading), create_letter_bag_system);
+    .add_systems(OnExit(GameStates::LoadingPacks), create_letter_bag_system);
 }
 
 #[derive(AssetCollection, Resource)]
@@ -34,7 +37,7 @@ pub struct LetterAssets {
     pub names: Vec<Handle<Name>>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub enum Effect {
     Score(i32),
     Money(i32),
@@ -42,6 +45,20 @@ pub enum Effect {
     Noop,
 }
 
+impl Effect {
+    // How hard the effect hits, roughly from 0 to 1, regardless of whether it helps or hurts.
+    pub fn severity(&self) -> f32 {
+        let severity = match self {
+            Effect::Score(score) => score.unsigned_abs() as f32 / 200.,
+            Effect::Money(money) => money.unsigned_abs() as f32 / 300.,
+            Effect::Income(income) => income.unsigned_abs() as f32 / 3.,
+            Effect::Noop => 0.,
+        };
+
+        severity.min(1.)
+    }
+}
+
 impl std::fmt::Display for Effect {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -60,12 +77,69 @@ pub struct TestimonialStub {
     pub targets: Vec<usize>,
 }
 
+impl TestimonialStub {
+    // Whether the stub has at least one target, and every target points at a word of the message.
+    pub fn has_valid_targets(&self) -> bool {
+        let word_count = self.message.split_whitespace().count();
+        !self.targets.is_empty() && self.targets.iter().all(|&target| target < word_count)
+    }
+
+    pub fn into_testimonial(self, name: Name, last_initial: char) -> Testimonial {
+        let effect = self.effect;
+        let first_name = name.first_name;
+        let pronouns = name.pronouns;
+        let message = self
+            .message
+            .replace("{pronoun_subject}", &pronouns.subject())
+            .replace("{pronoun_object}", &pronouns.object())
+            .replace("{pronoun_possessive}", &pronouns.possessive());
+
+        // TODO: target selection.
+        let target_word =
+            get_word_at_index(&message, self.targets[0]).expect("target word index must be valid");
+        let message = replace_word_with_underscores(message, self.targets[0]);
+        let message = format!("{} {}. {}.", first_name, last_initial, message);
+
+        let collected = false;
+        let lost = false;
+
+        Testimonial {
+            message,
+            effect,
+            target_word,
+            first_name,
+            last_initial,
+            pronouns,
+            collected,
+            lost,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Asset, TypePath, Clone)]
 pub struct Flavor {
     pub title: String,
     pub body: String,
     pub signoff: String,
     pub footer: String,
+    // Name of the scene theme that letters of this flavor are set in.
+    #[serde(default)]
+    pub theme: Option<String>,
+}
+
+impl Flavor {
+    // Body and signoff with their placeholders filled in.
+    pub fn interpolate(&self, recipients: usize, time_limit: usize) -> InterpolatedFlavor {
+        let fill = |text: &str| {
+            text.replace("{recipients}", &recipients.to_string())
+                .replace("{time_limit}", &time_limit.to_string())
+        };
+
+        InterpolatedFlavor {
+            body: fill(&self.body),
+            signoff: fill(&self.signoff),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Asset, TypePath, Clone)]
@@ -116,6 +190,20 @@ pub struct Testimonial {
     pub target_word: String,
     pub effect: Effect,
     pub collected: bool,
+    // Whether the word fell off screen and will not come back.
+    pub lost: bool,
+}
+
+impl Testimonial {
+    // Message with the blank filled in, if the target word has been collected.
+    pub fn revealed_message(&self) -> String {
+        if self.collected {
+            self.message
+                .replace(&"_".repeat(self.target_word.len()), &self.target_word)
+        } else {
+            self.message.clone()
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -134,6 +222,15 @@ pub struct Letter {
     pub curses: Vec<Testimonial>,
 }
 
+impl Letter {
+    // Whether every blessing has been guessed or lost, leaving nothing more to collect.
+    pub fn blessings_resolved(&self) -> bool {
+        self.blessings
+            .iter()
+            .all(|blessing| blessing.collected || blessing.lost)
+    }
+}
+
 #[derive(Resource, Deref, DerefMut, Debug)]
 pub struct CurrentLetter(pub Letter);
 
@@ -167,17 +264,7 @@ impl LetterBag {
             .choose(rng)
             .expect("TIME_LIMIT_RANGE must be a valid range");
 
-        let body = flavor
-            .body
-            .replace("{recipients}", &recipients.to_string())
-            .replace("{time_limit}", &time_limit.to_string());
-
-        let signoff = flavor
-            .signoff
-            .replace("{recipients}", &recipients.to_string())
-            .replace("{time_limit}", &time_limit.to_string());
-
-        let interpolated_flavor = InterpolatedFlavor { body, signoff };
+        let interpolated_flavor = flavor.interpolate(recipients, time_limit);
 
         let blessing_handles: Vec<_> = (0..blessing_amount)
             .map(|_| self.blessings.pick(rng).clone())
@@ -221,33 +308,8 @@ impl LetterBag {
             .get(&name_handle)
             .expect("name asset must be present")
             .to_owned();
-        let effect = testimonial.effect;
-        let first_name = name.first_name;
-        let pronouns = name.pronouns;
-        let last_initial = random_initial(rng);
-        let message = testimonial
-            .message
-            .replace("{pronoun_subject}", &pronouns.subject())
-            .replace("{pronoun_object}", &pronouns.object())
-            .replace("{pronoun_possessive}", &pronouns.possessive());
-
-        // TODO: target selection.
-        let target_word = get_word_at_index(&message, testimonial.targets[0])
-            .expect("target word index must be valid");
-        let message = replace_word_with_underscores(message, testimonial.targets[0]);
-        let message = format!("{} {}. {}.", first_name, last_initial, message);
-
-        let collected = false;
 
-        Testimonial {
-            message,
-            effect,
-            target_word,
-            first_name,
-            last_initial,
-            pronouns,
-            collected,
-        }
+        testimonial.into_testimonial(name, random_initial(rng))
     }
 }
 
@@ -265,7 +327,7 @@ impl WordBag {
             .iter()
             .map(|t| {
                 let color = Color::hsl(((rng.next_u32() % 180 + 165) % 360) as f32, 0.3, 0.4);
-                WordCube::new(&t.target_word, color, '+')
+                WordCube::new(&t.target_word, color, WordCubeKind::Blessing)
             })
             .collect();
 
@@ -273,7 +335,7 @@ impl WordBag {
             .iter()
             .map(|t| {
                 let color = Color::hsl(((rng.next_u32() % 180 + 165) % 360) as f32, 0.3, 0.4);
-                WordCube::new(&t.target_word, color, 'x')
+                WordCube::new(&t.target_word, color, WordCubeKind::Curse)
             })
             .collect();
 
@@ -281,7 +343,7 @@ impl WordBag {
             .iter()
             .map(|word| {
                 let color = Color::hsl(((rng.next_u32() % 180 + 165) % 360) as f32, 0.3, 0.4);
-                WordCube::new(word, color, '~')
+                WordCube::new(word, color, WordCubeKind::Decoy)
             })
             .collect();
 
@@ -299,7 +361,7 @@ impl WordBag {
     }
 }
 
-fn create_letter_bag_system(
+pub fn create_letter_bag_system(
     mut commands: Commands,
     letter_handles: Res<LetterAssets>,
     mut rng: Local<RngResource>,
@@ -5,25 +5,59 @@ use bevy::{
 use bevy_ratatui::RatatuiPlugins;
 use bevy_ratatui_camera::RatatuiCameraPlugin;
 
+mod achievements;
+mod analytics;
+#[cfg(not(feature = "windowed"))]
+mod authoring;
+mod capture;
+mod cli;
 mod constants;
+mod export;
 mod input;
 mod interface;
 mod letters;
 mod loading;
+mod metrics;
+mod packs;
+mod replay;
 mod rng;
 mod scene;
+mod settings;
+mod simulation;
 mod sound;
 mod states;
+mod storage;
 #[cfg(not(feature = "windowed"))]
 mod terminal;
 #[cfg(feature = "windowed")]
 mod windowed;
 mod word_checks;
 
+#[cfg(not(feature = "windowed"))]
+pub use authoring::AuthoringPlugin;
+
 pub struct AppPlugin;
 
 impl Plugin for AppPlugin {
     fn build(&self, app: &mut App) {
+        // Logging is set up first, so that messages from the plugins below aren't dropped.
+        app.add_plugins((
+            #[cfg(not(feature = "windowed"))]
+            terminal::logging_plugin,
+            #[cfg(feature = "windowed")]
+            windowed::logging_plugin,
+        ));
+
+        // Settings and asset sources must be in place before `DefaultPlugins` is added, and seeds
+        // before any system initializes its rng.
+        app.add_plugins((
+            cli::plugin,
+            rng::plugin,
+            replay::plugin,
+            settings::plugin,
+            packs::plugin,
+        ));
+
         app.add_plugins((
             #[cfg(not(feature = "windowed"))]
             terminal::plugin,
@@ -45,12 +79,18 @@ impl Plugin for AppPlugin {
         ));
 
         app.add_plugins((
+            achievements::plugin,
+            analytics::plugin,
+            capture::plugin,
+            export::plugin,
             interface::plugin,
             input::plugin,
             letters::plugin,
             loading::plugin,
+            metrics::plugin,
             sound::plugin,
             scene::plugin,
+            simulation::plugin,
             states::plugin,
             word_checks::plugin,
         ));
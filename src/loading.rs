@@ -4,14 +4,25 @@ use bevy_asset_loader::{
     standard_dynamic_asset::StandardDynamicAssetCollection,
 };
 
-use crate::{letters::LetterAssets, sound::SoundEffectAssets, states::GameStates};
+use crate::{
+    achievements::AchievementAssets,
+    interface::widgets::confetti::EmitterAssets,
+    letters::LetterAssets,
+    scene::{objects::SceneObjectAssets, theme::SceneThemeAssets},
+    sound::SoundEffectAssets,
+    states::GameStates,
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_loading_state(
         LoadingState::new(GameStates::Loading)
-            .continue_to_state(GameStates::Info)
+            .continue_to_state(GameStates::LoadingPacks)
             .with_dynamic_assets_file::<StandardDynamicAssetCollection>("assets.ron")
+            .load_collection::<AchievementAssets>()
+            .load_collection::<EmitterAssets>()
             .load_collection::<LetterAssets>()
+            .load_collection::<SceneObjectAssets>()
+            .load_collection::<SceneThemeAssets>()
             .load_collection::<SoundEffectAssets>(),
     );
 }
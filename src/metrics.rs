@@ -0,0 +1,225 @@
+use std::{path::PathBuf, time::Duration};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants::RESET_TALLY_TIME,
+    input::{GameAction, TranslateInput},
+    scene::spawning::BlessingMissed,
+    states::GameStates,
+    storage::{data_dir, read_ron, write_ron},
+    word_checks::{GuessKind, GuessOutcome},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(Metrics {
+        lifetime: TypingMetrics::load(),
+        ..default()
+    })
+    .init_resource::<ResetTally>()
+    .add_observer(guess_outcome_observer)
+    .add_observer(blessing_missed_observer)
+    .add_systems(OnEnter(GameStates::Printing), reset_letter_metrics_system)
+    .add_systems(
+        OnEnter(GameStates::Resetting),
+        (finish_letter_metrics_system, start_reset_tally_system),
+    )
+    .add_systems(
+        Update,
+        (
+            (count_keystrokes_system, typing_time_system)
+                .after(TranslateInput)
+                .run_if(in_state(GameStates::Playing)),
+            tick_reset_tally_system
+                .after(TranslateInput)
+                .run_if(in_state(GameStates::Resetting)),
+        ),
+    );
+}
+
+// Typing numbers for some span of play, which can be added together.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct TypingMetrics {
+    pub letters: u32,
+    pub characters_typed: u32,
+    // Backspaces and deletes of any kind.
+    pub corrections: u32,
+    pub guesses: u32,
+    // Guesses that collected a blessing.
+    pub correct_guesses: u32,
+    // Correct guesses of words that were on screen, which have a reaction time.
+    pub timed_guesses: u32,
+    // Total time from blessings appearing on screen to being guessed, in seconds.
+    pub reaction_seconds: f32,
+    // Time spent with the prompt open, in seconds.
+    pub typing_seconds: f32,
+    // Blessings whose words fell off screen before being guessed.
+    pub missed_blessings: u32,
+}
+
+impl TypingMetrics {
+    pub fn path() -> Option<PathBuf> {
+        data_dir().map(|dir| dir.join("metrics.ron"))
+    }
+
+    pub fn load() -> Self {
+        Self::path()
+            .and_then(|path| read_ron(&path))
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        if let Some(path) = Self::path() {
+            write_ron(&path, self);
+        }
+    }
+
+    pub fn add(&mut self, other: &TypingMetrics) {
+        self.letters += other.letters;
+        self.characters_typed += other.characters_typed;
+        self.corrections += other.corrections;
+        self.guesses += other.guesses;
+        self.correct_guesses += other.correct_guesses;
+        self.timed_guesses += other.timed_guesses;
+        self.reaction_seconds += other.reaction_seconds;
+        self.typing_seconds += other.typing_seconds;
+        self.missed_blessings += other.missed_blessings;
+    }
+
+    // Words per minute, counting every five characters typed as a word.
+    pub fn words_per_minute(&self) -> f32 {
+        if self.typing_seconds <= 0. {
+            return 0.;
+        }
+
+        self.characters_typed as f32 / 5. / (self.typing_seconds / 60.)
+    }
+
+    // Fraction of typed characters that were not corrected afterwards.
+    pub fn keystroke_accuracy(&self) -> f32 {
+        if self.characters_typed == 0 {
+            return 1.;
+        }
+
+        1. - (self.corrections as f32 / self.characters_typed as f32).min(1.)
+    }
+
+    pub fn guess_accuracy(&self) -> f32 {
+        if self.guesses == 0 {
+            return 1.;
+        }
+
+        self.correct_guesses as f32 / self.guesses as f32
+    }
+
+    pub fn average_reaction_time(&self) -> Option<Duration> {
+        (self.timed_guesses > 0)
+            .then(|| Duration::from_secs_f32(self.reaction_seconds / self.timed_guesses as f32))
+    }
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct Metrics {
+    // The letter being played, or the one just finished while its tally is up.
+    pub letter: TypingMetrics,
+    // Every letter since the game was launched.
+    pub run: TypingMetrics,
+    // Every letter ever played, kept in the data directory.
+    pub lifetime: TypingMetrics,
+}
+
+// Keeps the game between letters long enough to read the tally, unless it is skipped.
+#[derive(Resource, Debug, Default)]
+pub struct ResetTally {
+    timer: Timer,
+    skipped: bool,
+}
+
+impl ResetTally {
+    pub fn finished(&self) -> bool {
+        self.skipped || self.timer.finished()
+    }
+}
+
+fn reset_letter_metrics_system(mut metrics: ResMut<Metrics>) {
+    metrics.letter = TypingMetrics::default();
+}
+
+fn finish_letter_metrics_system(mut metrics: ResMut<Metrics>) {
+    let Metrics {
+        letter,
+        run,
+        lifetime,
+    } = metrics.as_mut();
+
+    letter.letters = 1;
+    run.add(letter);
+    lifetime.add(letter);
+    lifetime.save();
+}
+
+fn start_reset_tally_system(mut tally: ResMut<ResetTally>) {
+    *tally = ResetTally {
+        timer: Timer::from_seconds(RESET_TALLY_TIME, TimerMode::Once),
+        skipped: false,
+    };
+}
+
+fn tick_reset_tally_system(
+    mut tally: ResMut<ResetTally>,
+    mut actions: EventReader<GameAction>,
+    time: Res<Time>,
+) {
+    tally.timer.tick(time.delta());
+
+    if actions.read().any(|action| *action == GameAction::Confirm) {
+        tally.skipped = true;
+    }
+}
+
+fn count_keystrokes_system(
+    mut metrics: ResMut<Metrics>,
+    mut actions: EventReader<GameAction>,
+    time: Res<Time<Virtual>>,
+) {
+    if time.is_paused() {
+        actions.clear();
+        return;
+    }
+
+    for action in actions.read() {
+        match action {
+            GameAction::TypeChar(_) => metrics.letter.characters_typed += 1,
+            GameAction::Backspace
+            | GameAction::Delete
+            | GameAction::DeleteWord
+            | GameAction::DeleteToStart => metrics.letter.corrections += 1,
+            _ => {}
+        }
+    }
+}
+
+fn typing_time_system(mut metrics: ResMut<Metrics>, time: Res<Time>) {
+    metrics.letter.typing_seconds += time.delta_secs();
+}
+
+fn guess_outcome_observer(trigger: Trigger<GuessOutcome>, mut metrics: ResMut<Metrics>) {
+    let outcome = trigger.event();
+
+    metrics.letter.guesses += 1;
+
+    if outcome.kind == GuessKind::Blessing {
+        metrics.letter.correct_guesses += 1;
+
+        if let Some(reaction_time) = outcome.reaction_time {
+            metrics.letter.timed_guesses += 1;
+            metrics.letter.reaction_seconds += reaction_time.as_secs_f32();
+        }
+    }
+}
+
+fn blessing_missed_observer(_trigger: Trigger<BlessingMissed>, mut metrics: ResMut<Metrics>) {
+    metrics.letter.missed_blessings += 1;
+}
@@ -0,0 +1,290 @@
+use std::path::{Path, PathBuf};
+
+use bevy::{
+    asset::{LoadState, io::AssetSourceBuilder},
+    prelude::*,
+};
+use serde::Deserialize;
+
+use crate::{
+    letters::{Flavor, LetterAssets, Name, TestimonialStub, create_letter_bag_system},
+    settings::Settings,
+    states::GameStates,
+    storage::{config_dir, read_ron},
+};
+
+// Asset source that letter pack files are loaded through, i.e. "packs://my_pack/1.name.ron".
+pub const PACKS_SOURCE: &str = "packs";
+
+// File that must be present at the root of a pack directory for it to be discovered.
+pub const PACK_MANIFEST: &str = "pack.ron";
+
+const LETTER_EXTENSIONS: [&str; 5] = [
+    ".blessing.ron",
+    ".curse.ron",
+    ".decoy.ron",
+    ".flavor.ron",
+    ".name.ron",
+];
+
+// Must be added before `AssetPlugin`, so that the packs asset source can be registered.
+pub(super) fn plugin(app: &mut App) {
+    let letter_packs = match packs_dir() {
+        Some(packs_dir) if packs_dir.is_dir() => {
+            app.register_asset_source(
+                PACKS_SOURCE,
+                AssetSourceBuilder::platform_default(&packs_dir.to_string_lossy(), None),
+            );
+
+            LetterPacks::discover(&packs_dir, app.world().resource::<Settings>())
+        }
+        _ => LetterPacks::default(),
+    };
+
+    for pack in &letter_packs.0 {
+        let LetterPackManifest {
+            name,
+            author,
+            version,
+        } = &pack.manifest;
+        let status = if pack.enabled { "enabled" } else { "disabled" };
+        info!("found letter pack {name} v{version} by {author} ({status})");
+    }
+
+    app.insert_resource(letter_packs)
+        .add_systems(OnEnter(GameStates::LoadingPacks), load_letter_packs_system)
+        .add_systems(
+            Update,
+            wait_for_letter_packs_system.run_if(in_state(GameStates::LoadingPacks)),
+        )
+        .add_systems(
+            OnExit(GameStates::LoadingPacks),
+            merge_letter_packs_system.before(create_letter_bag_system),
+        );
+}
+
+pub fn packs_dir() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("packs"))
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct LetterPackManifest {
+    pub name: String,
+    pub author: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct LetterPack {
+    pub manifest: LetterPackManifest,
+    pub enabled: bool,
+    // Paths of letter files, relative to the packs directory.
+    pub files: Vec<String>,
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct LetterPacks(pub Vec<LetterPack>);
+
+impl LetterPacks {
+    pub fn discover(packs_dir: &Path, settings: &Settings) -> Self {
+        let Ok(entries) = std::fs::read_dir(packs_dir) else {
+            return Self::default();
+        };
+
+        let mut packs: Vec<_> = entries
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| {
+                let manifest: LetterPackManifest = read_ron(&entry.path().join(PACK_MANIFEST))?;
+                let enabled = !settings.disabled_packs.contains(&manifest.name);
+
+                let mut files = vec![];
+                collect_letter_files(
+                    &entry.path(),
+                    &entry.file_name().to_string_lossy(),
+                    &mut files,
+                );
+                files.sort();
+
+                Some(LetterPack {
+                    manifest,
+                    enabled,
+                    files,
+                })
+            })
+            .collect();
+
+        packs.sort_by(|a, b| a.manifest.name.cmp(&b.manifest.name));
+
+        Self(packs)
+    }
+
+    pub fn enabled_files(&self) -> impl Iterator<Item = &String> {
+        self.0
+            .iter()
+            .filter(|pack| pack.enabled)
+            .flat_map(|pack| &pack.files)
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct LetterPackAssets {
+    pub blessings: Vec<Handle<TestimonialStub>>,
+    pub curses: Vec<Handle<TestimonialStub>>,
+    pub decoys: Vec<Handle<TestimonialStub>>,
+    pub flavors: Vec<Handle<Flavor>>,
+    pub names: Vec<Handle<Name>>,
+}
+
+impl LetterPackAssets {
+    pub fn load(letter_packs: &LetterPacks, asset_server: &AssetServer) -> Self {
+        let mut pack_assets = Self::default();
+
+        for file in letter_packs.enabled_files() {
+            let path = format!("{PACKS_SOURCE}://{file}");
+
+            if file.ends_with(".blessing.ron") {
+                pack_assets.blessings.push(asset_server.load(path));
+            } else if file.ends_with(".curse.ron") {
+                pack_assets.curses.push(asset_server.load(path));
+            } else if file.ends_with(".decoy.ron") {
+                pack_assets.decoys.push(asset_server.load(path));
+            } else if file.ends_with(".flavor.ron") {
+                pack_assets.flavors.push(asset_server.load(path));
+            } else if file.ends_with(".name.ron") {
+                pack_assets.names.push(asset_server.load(path));
+            }
+        }
+
+        pack_assets
+    }
+
+    fn untyped_ids(&self) -> impl Iterator<Item = UntypedAssetId> {
+        self.blessings
+            .iter()
+            .chain(&self.curses)
+            .chain(&self.decoys)
+            .map(|handle| handle.id().untyped())
+            .chain(self.flavors.iter().map(|handle| handle.id().untyped()))
+            .chain(self.names.iter().map(|handle| handle.id().untyped()))
+    }
+}
+
+fn load_letter_packs_system(
+    mut commands: Commands,
+    letter_packs: Res<LetterPacks>,
+    asset_server: Res<AssetServer>,
+) {
+    commands.insert_resource(LetterPackAssets::load(&letter_packs, &asset_server));
+}
+
+// Pack files are loaded outside of `bevy_asset_loader`, since a single file that fails to load
+// would otherwise hold the whole loading state forever. Failed files are dropped when merging.
+fn wait_for_letter_packs_system(
+    pack_assets: Res<LetterPackAssets>,
+    asset_server: Res<AssetServer>,
+    mut next_state: ResMut<NextState<GameStates>>,
+) {
+    let settled = pack_assets.untyped_ids().all(|id| {
+        matches!(
+            asset_server.get_load_state(id),
+            Some(LoadState::Loaded | LoadState::Failed(_)) | None
+        )
+    });
+
+    if settled {
+        next_state.set(GameStates::Info);
+    }
+}
+
+fn merge_letter_packs_system(
+    pack_assets: Res<LetterPackAssets>,
+    stubs: Res<Assets<TestimonialStub>>,
+    flavors: Res<Assets<Flavor>>,
+    names: Res<Assets<Name>>,
+    mut letter_assets: ResMut<LetterAssets>,
+) {
+    let valid_stubs = |handles: &[Handle<TestimonialStub>]| -> Vec<Handle<TestimonialStub>> {
+        handles
+            .iter()
+            .filter(|handle| match stubs.get(*handle) {
+                Some(stub) if stub.has_valid_targets() => true,
+                Some(_) => {
+                    warn!(
+                        "skipping letter pack file {}: its target word indices are missing or \
+                         out of range",
+                        describe_handle(*handle)
+                    );
+                    false
+                }
+                None => {
+                    warn!(
+                        "skipping letter pack file {}: it failed to load",
+                        describe_handle(*handle)
+                    );
+                    false
+                }
+            })
+            .cloned()
+            .collect()
+    };
+
+    letter_assets
+        .blessings
+        .extend(valid_stubs(&pack_assets.blessings));
+    letter_assets
+        .curses
+        .extend(valid_stubs(&pack_assets.curses));
+    letter_assets
+        .decoys
+        .extend(valid_stubs(&pack_assets.decoys));
+    letter_assets
+        .flavors
+        .extend(loaded_handles(&pack_assets.flavors, &flavors));
+    letter_assets
+        .names
+        .extend(loaded_handles(&pack_assets.names, &names));
+}
+
+fn loaded_handles<A: Asset>(handles: &[Handle<A>], assets: &Assets<A>) -> Vec<Handle<A>> {
+    handles
+        .iter()
+        .filter(|handle| {
+            let loaded = assets.contains(*handle);
+            if !loaded {
+                warn!(
+                    "skipping letter pack file {}: it failed to load",
+                    describe_handle(*handle)
+                );
+            }
+            loaded
+        })
+        .cloned()
+        .collect()
+}
+
+fn describe_handle<A: Asset>(handle: &Handle<A>) -> String {
+    handle
+        .path()
+        .map_or_else(|| format!("{handle:?}"), ToString::to_string)
+}
+
+fn collect_letter_files(directory: &Path, relative: &str, files: &mut Vec<String>) {
+    let Ok(entries) = std::fs::read_dir(directory) else {
+        return;
+    };
+
+    for entry in entries.filter_map(Result::ok) {
+        let file_name = entry.file_name().to_string_lossy().into_owned();
+        let relative_path = format!("{relative}/{file_name}");
+
+        if entry.path().is_dir() {
+            collect_letter_files(&entry.path(), &relative_path, files);
+        } else if LETTER_EXTENSIONS
+            .iter()
+            .any(|extension| file_name.ends_with(extension))
+        {
+            files.push(relative_path);
+        }
+    }
+}
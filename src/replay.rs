@@ -0,0 +1,221 @@
+use std::{
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    capture::LastFrame,
+    cli::CliArgs,
+    input::{GameAction, TranslateInput},
+    rng::RngSeed,
+    states::GameStates,
+    storage::{data_dir, read_ron, write_ron},
+};
+
+// Length of every frame while recording or replaying, so that both runs simulate identically.
+const REPLAY_TIMESTEP: f64 = 1. / 90.;
+
+pub(super) fn plugin(app: &mut App) {
+    let cli_args = app.world().resource::<CliArgs>().clone();
+
+    app.init_resource::<ReplayClock>()
+        .add_systems(OnExit(GameStates::Loading), start_replay_clock_system)
+        .add_systems(Last, tick_replay_clock_system);
+
+    if let Some(path) = cli_args.replay {
+        let Some(replay) = read_ron::<Replay>(&path) else {
+            warn!("could not read replay {}", path.display());
+            return;
+        };
+
+        info!(
+            "replaying {} actions from {}",
+            replay.events.len(),
+            path.display()
+        );
+
+        app.insert_resource(RngSeed::new(replay.seed))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+                replay.timestep,
+            )))
+            .insert_resource(ReplayPlayback {
+                replay,
+                next_event: 0,
+            })
+            .add_systems(Startup, apply_simulation_timestep_system)
+            .add_systems(Update, feed_replay_system.in_set(TranslateInput));
+    } else if cli_args.record_replay {
+        let Some(directory) = data_dir().map(|dir| dir.join("replays")) else {
+            warn!("recording replays is not supported on this platform");
+            return;
+        };
+
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        let path = directory.join(format!("{seconds}.replay.ron"));
+        let seed = cli_args.seed.unwrap_or_else(rand::random);
+
+        info!("recording replay to {}", path.display());
+
+        app.insert_resource(RngSeed::new(seed))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+                REPLAY_TIMESTEP,
+            )))
+            .insert_resource(ReplayRecording {
+                path,
+                replay: Replay {
+                    seed,
+                    timestep: REPLAY_TIMESTEP,
+                    simulation_timestep: None,
+                    terminal_size: (0, 0),
+                    events: vec![],
+                },
+            })
+            .add_systems(Startup, record_simulation_timestep_system)
+            .add_systems(
+                Update,
+                (
+                    record_replay_system,
+                    save_replay_system.run_if(state_changed::<GameStates>),
+                )
+                    .chain()
+                    .after(TranslateInput),
+            )
+            .add_systems(Last, save_replay_system.run_if(on_event::<AppExit>));
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Replay {
+    pub seed: u64,
+    // Length of each frame, in seconds.
+    pub timestep: f64,
+    // Length of each gameplay step, in seconds. Replays without one use the current settings.
+    #[serde(default)]
+    pub simulation_timestep: Option<f64>,
+    // Spawn positions depend on the size of the scene, so replays only match at the same size.
+    pub terminal_size: (u16, u16),
+    pub events: Vec<ReplayEvent>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReplayEvent {
+    // Frames since the game finished loading.
+    pub frame: u64,
+    pub action: GameAction,
+}
+
+// Counts frames from the end of loading, since loading takes a different number of frames on
+// every run.
+#[derive(Resource, Default, Debug)]
+pub struct ReplayClock(Option<u64>);
+
+#[derive(Resource, Debug)]
+struct ReplayRecording {
+    path: PathBuf,
+    replay: Replay,
+}
+
+#[derive(Resource, Debug)]
+pub struct ReplayPlayback {
+    replay: Replay,
+    next_event: usize,
+}
+
+// Live input is ignored while a replay is playing, so that it cannot change the outcome.
+pub fn live_input(playback: Option<Res<ReplayPlayback>>) -> bool {
+    playback.is_none()
+}
+
+fn start_replay_clock_system(mut clock: ResMut<ReplayClock>) {
+    clock.0 = Some(0);
+}
+
+fn tick_replay_clock_system(mut clock: ResMut<ReplayClock>) {
+    if let Some(frame) = clock.0.as_mut() {
+        *frame += 1;
+    }
+}
+
+fn record_simulation_timestep_system(
+    mut recording: ResMut<ReplayRecording>,
+    fixed_time: Res<Time<Fixed>>,
+) {
+    recording.replay.simulation_timestep = Some(fixed_time.timestep().as_secs_f64());
+}
+
+// Gameplay only steps the same way at the rate the replay was recorded at.
+fn apply_simulation_timestep_system(
+    playback: Res<ReplayPlayback>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+) {
+    if let Some(timestep) = playback.replay.simulation_timestep {
+        fixed_time.set_timestep(Duration::from_secs_f64(timestep));
+    }
+}
+
+fn record_replay_system(
+    mut recording: ResMut<ReplayRecording>,
+    clock: Res<ReplayClock>,
+    mut actions: EventReader<GameAction>,
+    last_frame: Res<LastFrame>,
+) {
+    let Some(frame) = clock.0 else {
+        actions.clear();
+        return;
+    };
+
+    let mut recorded = false;
+    for action in actions.read().filter(|action| action.replayed()) {
+        recording.replay.events.push(ReplayEvent {
+            frame,
+            action: *action,
+        });
+        recorded = true;
+    }
+
+    if recorded {
+        recording.replay.terminal_size = (last_frame.area.width, last_frame.area.height);
+    }
+}
+
+// Recorded actions are kept in memory, and written out between letters and when the game exits.
+fn save_replay_system(recording: Res<ReplayRecording>) {
+    write_ron(&recording.path, &recording.replay);
+}
+
+fn feed_replay_system(
+    mut playback: ResMut<ReplayPlayback>,
+    clock: Res<ReplayClock>,
+    mut actions: EventWriter<GameAction>,
+    last_frame: Res<LastFrame>,
+) {
+    let Some(frame) = clock.0 else {
+        return;
+    };
+
+    if frame == 0 {
+        let (width, height) = playback.replay.terminal_size;
+        if (last_frame.area.width, last_frame.area.height) != (width, height) {
+            warn!("replay was recorded at {width}x{height}, results may differ at this size");
+        }
+    }
+
+    while let Some(event) = playback.replay.events.get(playback.next_event) {
+        if event.frame > frame {
+            break;
+        }
+
+        actions.write(event.action);
+        playback.next_event += 1;
+
+        if playback.next_event == playback.replay.events.len() {
+            info!("replay finished");
+        }
+    }
+}
@@ -2,11 +2,46 @@ use bevy::prelude::*;
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
 
+use crate::cli::CliArgs;
+
+pub(super) fn plugin(app: &mut App) {
+    if let Some(seed) = app.world().resource::<CliArgs>().seed {
+        app.insert_resource(RngSeed::new(seed));
+    }
+}
+
+// When present, every `RngResource` is derived from this seed instead of from entropy.
+#[derive(Resource, Debug)]
+pub struct RngSeed {
+    pub seed: u64,
+    next_stream: u64,
+}
+
+impl RngSeed {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            next_stream: 0,
+        }
+    }
+}
+
 #[derive(Resource, Deref, DerefMut)]
 pub struct RngResource(pub ChaCha8Rng);
 
-impl Default for RngResource {
-    fn default() -> Self {
-        Self(ChaCha8Rng::from_entropy())
+// Each seeded rng gets its own stream, numbered in the order the rngs are created. That order
+// follows how plugins and systems are registered, so runs of the same build with the same seed draw
+// the same numbers, but reordering plugins can hand a system a different stream.
+impl FromWorld for RngResource {
+    fn from_world(world: &mut World) -> Self {
+        match world.get_resource_mut::<RngSeed>() {
+            Some(mut rng_seed) => {
+                let mut rng = ChaCha8Rng::seed_from_u64(rng_seed.seed);
+                rng.set_stream(rng_seed.next_stream);
+                rng_seed.next_stream += 1;
+                Self(rng)
+            }
+            None => Self(ChaCha8Rng::from_entropy()),
+        }
     }
 }
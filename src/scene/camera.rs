@@ -1,22 +1,62 @@
 use bevy::prelude::*;
-use bevy_ratatui_camera::{RatatuiCamera, RatatuiCameraStrategy};
+use bevy_ratatui_camera::{RatatuiCamera, RatatuiCameraEdgeDetection, RatatuiCameraStrategy};
+use serde::{Deserialize, Serialize};
 
-use crate::states::GameStates;
+use crate::{settings::Settings, states::GameStates};
 
 pub fn plugin(app: &mut App) {
     app.add_systems(OnExit(GameStates::Loading), camera_setup_system);
 }
 
-fn camera_setup_system(mut commands: Commands) {
-    commands.spawn((
+// How the 3D scene is turned into terminal characters.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RenderStyle {
+    // Lit surfaces filled in with text from the current letter.
+    #[default]
+    LetterText,
+    // Characters from a ramp of increasingly dense symbols, by brightness.
+    Luminance,
+    // Colored half-block cells, two pixels per cell.
+    HalfBlocks,
+    // Outlines of every surface, over a faint luminance ramp.
+    Edges,
+}
+
+impl RenderStyle {
+    // Character the camera fills lit surfaces with, for post-processing to replace.
+    pub const FILL_CHARACTER: char = '@';
+
+    fn strategy(self) -> RatatuiCameraStrategy {
+        match self {
+            RenderStyle::LetterText => {
+                RatatuiCameraStrategy::luminance_with_characters(&[' ', Self::FILL_CHARACTER])
+            }
+            RenderStyle::Luminance => RatatuiCameraStrategy::luminance_with_characters(&[
+                ' ', '.', ':', '-', '=', '+', '*', '#', '%', '@',
+            ]),
+            // Half blocks are the camera's default strategy.
+            RenderStyle::HalfBlocks => RatatuiCameraStrategy::default(),
+            RenderStyle::Edges => {
+                RatatuiCameraStrategy::luminance_with_characters(&[' ', '.', ':'])
+            }
+        }
+    }
+}
+
+fn camera_setup_system(mut commands: Commands, settings: Res<Settings>) {
+    let mut camera = commands.spawn((
         Camera {
             order: 1,
             ..default()
         },
         RatatuiCamera::default(),
-        RatatuiCameraStrategy::luminance_with_characters(&[' ', '@']),
+        settings.render_style.strategy(),
         Camera3d::default(),
         Transform::from_xyz(0.0, 0.0, 1.0).looking_at(Vec3::ZERO, Vec3::Y),
         Msaa::Off,
     ));
+
+    if settings.render_style == RenderStyle::Edges {
+        camera.insert(RatatuiCameraEdgeDetection::default());
+    }
 }
@@ -1,8 +1,19 @@
 use bevy::prelude::*;
 
 pub mod camera;
+pub mod motion;
+pub mod objects;
 pub mod spawning;
+pub mod theme;
+pub mod word_mesh;
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_plugins((camera::plugin, spawning::plugin));
+    app.add_plugins((
+        camera::plugin,
+        motion::plugin,
+        objects::plugin,
+        spawning::plugin,
+        theme::plugin,
+        word_mesh::plugin,
+    ));
 }
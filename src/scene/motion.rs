@@ -0,0 +1,247 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use rand::{Rng, seq::SliceRandom};
+
+use crate::{
+    constants::{LETTER_MOTION_PATTERNS, WORD_CUBE_FALL_SPEED},
+    rng::RngResource,
+    settings::{Difficulty, Settings},
+    simulation::Interpolated,
+    states::GameStates,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<LetterMotion>()
+        .init_resource::<MotionFreeze>()
+        .add_systems(OnEnter(GameStates::Printing), pick_letter_motion_system)
+        .add_systems(
+            FixedUpdate,
+            word_cube_move_system.run_if(in_state(GameStates::Playing)),
+        );
+}
+
+// Ways word cubes can move, a few of which are picked for each letter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MotionPattern {
+    Fall,
+    Sine,
+    Diagonal,
+    Spiral,
+    Surge,
+    Hover,
+    // Several cubes spawned together in a formation, drifting as one.
+    Swarm,
+}
+
+impl MotionPattern {
+    // Patterns that letters can pick from at each difficulty.
+    fn pool(difficulty: Difficulty) -> &'static [MotionPattern] {
+        match difficulty {
+            Difficulty::Easy => &[
+                MotionPattern::Fall,
+                MotionPattern::Sine,
+                MotionPattern::Hover,
+            ],
+            Difficulty::Normal => &[
+                MotionPattern::Fall,
+                MotionPattern::Sine,
+                MotionPattern::Diagonal,
+                MotionPattern::Surge,
+                MotionPattern::Hover,
+                MotionPattern::Swarm,
+            ],
+            Difficulty::Hard => &[
+                MotionPattern::Sine,
+                MotionPattern::Diagonal,
+                MotionPattern::Spiral,
+                MotionPattern::Surge,
+                MotionPattern::Swarm,
+            ],
+        }
+    }
+}
+
+// While running, stops everything with a `CubeMotion` in place.
+#[derive(Resource, Debug, Default)]
+pub struct MotionFreeze(pub Option<Timer>);
+
+// Motion patterns picked for the current letter.
+#[derive(Resource, Debug)]
+pub struct LetterMotion(pub Vec<MotionPattern>);
+
+impl Default for LetterMotion {
+    fn default() -> Self {
+        Self(vec![MotionPattern::Fall])
+    }
+}
+
+impl LetterMotion {
+    pub fn pick(&self, rng: &mut impl Rng) -> MotionPattern {
+        *self
+            .0
+            .choose(rng)
+            .expect("letter should have at least one motion pattern")
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum MotionKind {
+    Fall,
+    Sine {
+        amplitude: f32,
+        frequency: f32,
+        phase: f32,
+    },
+    Diagonal {
+        drift: f32,
+    },
+    Spiral {
+        radius: f32,
+        angular_speed: f32,
+        phase: f32,
+    },
+    // Speeds up and brakes in turn.
+    Surge {
+        frequency: f32,
+        phase: f32,
+    },
+    // Stops for a while once it reaches the middle of the screen.
+    Hover {
+        remaining: f32,
+    },
+}
+
+impl MotionKind {
+    // Random parameters for a pattern, shared by every cube in a swarm.
+    pub fn sample(pattern: MotionPattern, rng: &mut impl Rng) -> Self {
+        match pattern {
+            MotionPattern::Fall => MotionKind::Fall,
+            MotionPattern::Sine => MotionKind::Sine {
+                amplitude: rng.gen_range(0.2..0.6),
+                frequency: rng.gen_range(1.0..2.5),
+                phase: rng.gen_range(0.0..TAU),
+            },
+            MotionPattern::Diagonal => MotionKind::Diagonal {
+                drift: rng.gen_range(0.1..0.3) * if rng.gen_bool(0.5) { 1. } else { -1. },
+            },
+            MotionPattern::Spiral => MotionKind::Spiral {
+                radius: rng.gen_range(0.2..0.5),
+                angular_speed: rng.gen_range(1.5..3.0),
+                phase: rng.gen_range(0.0..TAU),
+            },
+            MotionPattern::Surge => MotionKind::Surge {
+                frequency: rng.gen_range(0.8..1.6),
+                phase: rng.gen_range(0.0..TAU),
+            },
+            MotionPattern::Hover => MotionKind::Hover {
+                remaining: rng.gen_range(1.5..3.0),
+            },
+            MotionPattern::Swarm => MotionKind::Sine {
+                amplitude: rng.gen_range(0.3..0.5),
+                frequency: rng.gen_range(0.8..1.5),
+                phase: rng.gen_range(0.0..TAU),
+            },
+        }
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CubeMotion {
+    pub kind: MotionKind,
+    // Point the cube moves around, which falls down the screen.
+    anchor: Vec3,
+    age: f32,
+}
+
+impl CubeMotion {
+    pub fn new(kind: MotionKind, position: Vec3) -> Self {
+        Self {
+            kind,
+            anchor: position,
+            age: 0.,
+        }
+    }
+
+    // Advances the motion by `delta` seconds, returning the new position of the cube.
+    fn step(&mut self, delta: f32) -> Vec3 {
+        let speed = match &mut self.kind {
+            MotionKind::Surge { frequency, phase } => {
+                1. + 0.8 * (self.age * *frequency + *phase).sin()
+            }
+            MotionKind::Hover { remaining } if self.anchor.y <= 0. && *remaining > 0. => {
+                *remaining -= delta;
+                0.
+            }
+            _ => 1.,
+        };
+
+        self.age += delta;
+        self.anchor.y -= delta * WORD_CUBE_FALL_SPEED * speed;
+
+        if let MotionKind::Diagonal { drift } = self.kind {
+            self.anchor.x += delta * drift;
+        }
+
+        self.anchor + self.offset()
+    }
+
+    fn offset(&self) -> Vec3 {
+        match self.kind {
+            MotionKind::Sine {
+                amplitude,
+                frequency,
+                phase,
+            } => Vec3::X * amplitude * (self.age * frequency + phase).sin(),
+            MotionKind::Spiral {
+                radius,
+                angular_speed,
+                phase,
+            } => {
+                let angle = self.age * angular_speed + phase;
+                Vec3::new(angle.cos(), angle.sin(), 0.) * radius
+            }
+            _ => Vec3::ZERO,
+        }
+    }
+}
+
+fn pick_letter_motion_system(
+    mut letter_motion: ResMut<LetterMotion>,
+    settings: Res<Settings>,
+    mut rng: Local<RngResource>,
+) {
+    let pool = MotionPattern::pool(settings.difficulty);
+
+    letter_motion.0 = pool
+        .choose_multiple(&mut rng.0, LETTER_MOTION_PATTERNS)
+        .copied()
+        .collect();
+}
+
+fn word_cube_move_system(
+    time: Res<Time>,
+    mut stars: Query<(&mut Interpolated, &mut CubeMotion)>,
+    mut freeze: ResMut<MotionFreeze>,
+) {
+    if let Some(timer) = &mut freeze.0 {
+        if !timer.tick(time.delta()).finished() {
+            // Still step in place, so that nothing is blended towards an old position.
+            for (mut star, _) in &mut stars {
+                let current = star.current;
+                star.step(current);
+            }
+            return;
+        }
+
+        freeze.0 = None;
+    }
+
+    for (mut star, mut motion) in &mut stars {
+        let mut transform = star.current;
+        transform.translation = motion.step(time.delta_secs());
+        transform.rotate_y(time.delta_secs());
+        transform.rotate_x(time.delta_secs() * 0.4);
+        star.step(transform);
+    }
+}
@@ -0,0 +1,259 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, time::common_conditions::on_timer};
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_common_assets::ron::RonAssetPlugin;
+use bevy_ratatui_camera::RatatuiCamera;
+use rand::{Rng, seq::SliceRandom};
+use serde::Deserialize;
+
+use crate::{
+    constants::{MAC_CYAN_COLOR, OBJECT_SPAWN_INTERVAL},
+    interface::widgets::confetti::{ConfettiSpawn, EmitterPreset, ParticleOrigin},
+    rng::RngResource,
+    simulation::Interpolated,
+    sound::SoundEffect,
+    states::GameStates,
+};
+
+use super::{
+    motion::{CubeMotion, MotionFreeze, MotionKind},
+    spawning::{WordCube, WordCubeKind, get_lowest_visible_y, get_spawn_position},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins(RonAssetPlugin::<SceneObjectStub>::new(&["object.ron"]))
+        .add_observer(power_up_activated_observer)
+        .add_systems(
+            FixedUpdate,
+            (
+                scene_object_spawn_system
+                    .run_if(on_timer(Duration::from_millis(OBJECT_SPAWN_INTERVAL))),
+                scene_object_despawn_system,
+            )
+                .run_if(in_state(GameStates::Playing)),
+        )
+        .add_systems(OnExit(GameStates::Resetting), clear_scene_objects_system);
+}
+
+#[derive(AssetCollection, Resource)]
+pub struct SceneObjectAssets {
+    #[asset(key = "objects", collection(typed))]
+    pub objects: Vec<Handle<SceneObjectStub>>,
+}
+
+// Definition of a non-word object that can appear in the scene.
+#[derive(Debug, Deserialize, Asset, TypePath, Clone)]
+pub struct SceneObjectStub {
+    pub kind: SceneObjectKind,
+    pub shape: ObjectShape,
+    pub color: (f32, f32, f32),
+    // Chance of spawning whenever this object is picked, from 0 to 1.
+    pub spawn_chance: f64,
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub enum SceneObjectKind {
+    // Activated by submitting its command word while it is on screen.
+    PowerUp {
+        command: String,
+        effect: PowerUpEffect,
+    },
+    // Floats in front of the word cubes, hiding the labels behind it.
+    Obstacle,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+pub enum PowerUpEffect {
+    // Stops every word cube in place for a number of seconds.
+    Freeze(f32),
+    // Clears every decoy off the screen.
+    Sweep,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub enum ObjectShape {
+    Sphere {
+        radius: f32,
+    },
+    Torus {
+        minor_radius: f32,
+        major_radius: f32,
+    },
+}
+
+impl ObjectShape {
+    fn mesh(&self) -> Mesh {
+        match *self {
+            ObjectShape::Sphere { radius } => Sphere::new(radius).into(),
+            ObjectShape::Torus {
+                minor_radius,
+                major_radius,
+            } => Torus {
+                minor_radius,
+                major_radius,
+            }
+            .into(),
+        }
+    }
+
+    // Distance from the center to the outer edge.
+    fn radius(&self) -> f32 {
+        match *self {
+            ObjectShape::Sphere { radius } => radius,
+            ObjectShape::Torus {
+                minor_radius,
+                major_radius,
+            } => major_radius + minor_radius,
+        }
+    }
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct SceneObject {
+    pub kind: SceneObjectKind,
+    pub radius: f32,
+}
+
+impl SceneObject {
+    pub fn command(&self) -> Option<&str> {
+        match &self.kind {
+            SceneObjectKind::PowerUp { command, .. } => Some(command),
+            SceneObjectKind::Obstacle => None,
+        }
+    }
+}
+
+#[derive(Event, Debug)]
+pub struct PowerUpActivated(pub Entity);
+
+fn scene_object_spawn_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    object_assets: Res<SceneObjectAssets>,
+    object_stubs: Res<Assets<SceneObjectStub>>,
+    scene_objects: Query<&SceneObject>,
+    mut rng: Local<RngResource>,
+    camera: Single<(&Camera, &GlobalTransform), With<RatatuiCamera>>,
+) {
+    let Some(stub) = object_assets
+        .objects
+        .choose(&mut rng.0)
+        .and_then(|handle| object_stubs.get(handle))
+    else {
+        return;
+    };
+
+    // Only one obstacle, and one of each power-up, is on screen at a time.
+    if !rng.0.gen_bool(stub.spawn_chance.clamp(0., 1.))
+        || scene_objects
+            .iter()
+            .any(|scene_object| scene_object.kind == stub.kind)
+    {
+        return;
+    }
+
+    // Obstacles spawn nearer the camera than word cubes, so that they can cover them.
+    let (near_depth, far_depth) = match stub.kind {
+        SceneObjectKind::Obstacle => (1.2, 1.8),
+        SceneObjectKind::PowerUp { .. } => (2.0, 4.0),
+    };
+
+    let (camera, camera_transform) = camera.into_inner();
+    let Some(position) =
+        get_spawn_position(camera, camera_transform, near_depth, far_depth, &mut rng)
+    else {
+        return;
+    };
+
+    let (red, green, blue) = stub.color;
+    commands.spawn((
+        Mesh3d(meshes.add(stub.shape.mesh())),
+        MeshMaterial3d(materials.add(Color::srgb(red, green, blue))),
+        Transform::from_translation(position),
+        Interpolated::new(Transform::from_translation(position)),
+        CubeMotion::new(MotionKind::Fall, position),
+        SceneObject {
+            kind: stub.kind.clone(),
+            radius: stub.shape.radius(),
+        },
+    ));
+}
+
+fn scene_object_despawn_system(
+    mut commands: Commands,
+    scene_objects: Query<(Entity, &Interpolated), With<SceneObject>>,
+    camera: Single<(&Camera, &GlobalTransform), With<RatatuiCamera>>,
+) {
+    let (camera, camera_transform) = camera.into_inner();
+
+    for (entity, interpolated) in &scene_objects {
+        let translation = interpolated.current.translation;
+        // Objects spawn at different depths, so the bottom is measured at each one's own depth.
+        let Some(lowest_visible_y) = get_lowest_visible_y(camera, camera_transform, -translation.z)
+        else {
+            continue;
+        };
+
+        if translation.y < lowest_visible_y {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn power_up_activated_observer(
+    trigger: Trigger<PowerUpActivated>,
+    mut commands: Commands,
+    scene_objects: Query<(&SceneObject, &Transform)>,
+    word_cubes: Query<(Entity, &WordCube, &Transform)>,
+    mut freeze: ResMut<MotionFreeze>,
+) {
+    let entity = trigger.event().0;
+    let Ok((scene_object, transform)) = scene_objects.get(entity) else {
+        return;
+    };
+    let SceneObjectKind::PowerUp { effect, .. } = scene_object.kind else {
+        return;
+    };
+
+    commands.entity(entity).despawn();
+    commands.trigger(SoundEffect::GuessBless);
+    commands.trigger(ConfettiSpawn {
+        preset: EmitterPreset::Fountain,
+        origin: ParticleOrigin::World(transform.translation),
+        color: MAC_CYAN_COLOR,
+        character: '*',
+    });
+
+    match effect {
+        PowerUpEffect::Freeze(seconds) => {
+            freeze.0 = Some(Timer::from_seconds(seconds, TimerMode::Once));
+        }
+        PowerUpEffect::Sweep => {
+            for (entity, word_cube, transform) in &word_cubes {
+                if word_cube.kind == WordCubeKind::Decoy {
+                    commands.entity(entity).despawn();
+                    commands.trigger(ConfettiSpawn {
+                        preset: EmitterPreset::Burst,
+                        origin: ParticleOrigin::World(transform.translation),
+                        color: MAC_CYAN_COLOR,
+                        character: word_cube.despawn_character,
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn clear_scene_objects_system(
+    mut commands: Commands,
+    scene_objects: Query<Entity, With<SceneObject>>,
+    mut freeze: ResMut<MotionFreeze>,
+) {
+    for entity in &scene_objects {
+        commands.entity(entity).despawn();
+    }
+
+    *freeze = MotionFreeze::default();
+}
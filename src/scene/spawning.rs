@@ -4,18 +4,33 @@ use bevy::{prelude::*, time::common_conditions::on_timer};
 use bevy_ratatui_camera::RatatuiCamera;
 use rand::distributions::uniform::SampleRange;
 
-use crate::{constants::WORD_CUBE_LENGTH, letters::WordBag, rng::RngResource, states::GameStates};
+use crate::{
+    constants::{SWARM_SIZE, WORD_CUBE_LENGTH},
+    letters::{CurrentLetter, WordBag},
+    rng::RngResource,
+    settings::Settings,
+    simulation::Interpolated,
+    states::GameStates,
+};
+
+use super::{
+    motion::{CubeMotion, LetterMotion, MotionKind, MotionPattern},
+    word_mesh::{WordMeshes, word_mesh},
+};
 
 pub fn plugin(app: &mut App) {
     app.add_systems(OnExit(GameStates::Loading), scene_setup_system)
         .add_systems(
-            Update,
+            FixedUpdate,
             (
                 word_cube_spawn_system.run_if(on_timer(Duration::from_millis(1000))),
-                word_cube_move_system,
                 word_cube_despawn_system,
             )
                 .run_if(in_state(GameStates::Playing)),
+        )
+        .add_systems(
+            Update,
+            word_cube_urgency_system.run_if(in_state(GameStates::Playing)),
         );
 }
 
@@ -24,20 +39,55 @@ pub struct WordCube {
     pub word: String,
     pub color: Color,
     pub despawn_character: char,
+    pub kind: WordCubeKind,
+}
+
+// Which kind of testimonial a word cube's word belongs to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WordCubeKind {
+    Blessing,
+    Curse,
+    #[default]
+    Decoy,
+}
+
+// Virtual time at which a word cube appeared, for measuring how long it took to guess.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SpawnedAt(pub Duration);
+
+// How far a word cube is from its spawn height to falling off screen, from 0 to 1.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Urgency {
+    start_y: f32,
+    pub level: f32,
+}
+
+// An uncollected blessing's word cube fell off screen before it was guessed.
+#[derive(Event, Debug, Clone)]
+pub struct BlessingMissed {
+    pub word: String,
 }
 
 impl WordCube {
-    pub fn new(word: &str, color: Color, despawn_character: char) -> Self {
+    pub fn new(word: &str, color: Color, kind: WordCubeKind) -> Self {
+        let despawn_character = match kind {
+            WordCubeKind::Blessing => '+',
+            WordCubeKind::Curse => 'x',
+            WordCubeKind::Decoy => '~',
+        };
+
         Self {
             word: word.into(),
             color,
             despawn_character,
+            kind,
         }
     }
 }
 
+// Plain light at the camera until the first letter swaps in its theme.
 fn scene_setup_system(mut commands: Commands) {
-    commands.spawn((PointLight::default(),));
+    commands.spawn((PointLight::default(), ThemeEntity));
 }
 
 fn word_cube_spawn_system(
@@ -46,8 +96,11 @@ fn word_cube_spawn_system(
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut word_bag: ResMut<WordBag>,
     word_cubes: Query<&WordCube>,
+    letter_motion: Res<LetterMotion>,
     mut rng: Local<RngResource>,
     camera: Single<(&Camera, &GlobalTransform), With<RatatuiCamera>>,
+    time: Res<Time<Virtual>>,
+    settings: Res<Settings>,
 ) {
     let (camera, camera_transform) = camera.into_inner();
     let Some(spawn_position) = get_spawn_position(camera, camera_transform, 2.0, 4.0, &mut rng)
@@ -55,50 +108,118 @@ fn word_cube_spawn_system(
         return;
     };
 
-    let word_cube = word_bag.pick(&mut rng.0).clone();
+    let pattern = letter_motion.pick(&mut rng.0);
+    let kind = MotionKind::sample(pattern, &mut rng.0);
 
-    if word_cubes
-        .iter()
-        .any(|spawned_cube| word_cube.word == spawned_cube.word)
-    {
-        word_bag.shuffle_new_draft(&mut rng.0);
-        return;
+    // A swarm is a V of cubes led by the one at the spawn position.
+    let offsets: Vec<_> = if pattern == MotionPattern::Swarm {
+        (0..SWARM_SIZE)
+            .map(|index| {
+                let rank = index.div_ceil(2) as f32;
+                let side = if index % 2 == 0 { 1. } else { -1. };
+                Vec3::new(
+                    side * rank * WORD_CUBE_LENGTH * 1.5,
+                    rank * WORD_CUBE_LENGTH,
+                    0.,
+                )
+            })
+            .collect()
+    } else {
+        vec![Vec3::ZERO]
+    };
+
+    // Every word is picked up front, so that a duplicate never leaves a partial swarm behind.
+    let mut picked_cubes: Vec<WordCube> = vec![];
+    for _ in &offsets {
+        let word_cube = word_bag.pick(&mut rng.0).clone();
+
+        if word_cubes
+            .iter()
+            .chain(&picked_cubes)
+            .any(|spawned_cube| word_cube.word == spawned_cube.word)
+        {
+            word_bag.shuffle_new_draft(&mut rng.0);
+            return;
+        }
+
+        picked_cubes.push(word_cube);
     }
 
-    commands.spawn((
-        Mesh3d(meshes.add(Cuboid::from_length(WORD_CUBE_LENGTH))),
-        MeshMaterial3d(materials.add(word_cube.color)),
-        Transform::from_translation(spawn_position),
-        word_cube,
-    ));
+    for (offset, word_cube) in offsets.into_iter().zip(picked_cubes) {
+        let position = spawn_position + offset;
+        let cube_mesh = meshes.add(Cuboid::from_length(WORD_CUBE_LENGTH));
+        let word_meshes = settings.word_meshes.then(|| WordMeshes {
+            cube: cube_mesh.clone(),
+            text: meshes.add(word_mesh(&word_cube.word)),
+        });
+
+        let mut entity = commands.spawn((
+            Mesh3d(cube_mesh),
+            MeshMaterial3d(materials.add(word_cube.color)),
+            Transform::from_translation(position),
+            Interpolated::new(Transform::from_translation(position)),
+            CubeMotion::new(kind, position),
+            Urgency {
+                start_y: position.y,
+                level: 0.,
+            },
+            word_cube,
+            SpawnedAt(time.elapsed()),
+        ));
+        if let Some(word_meshes) = word_meshes {
+            entity.insert(word_meshes);
+        }
+    }
 }
 
-fn word_cube_move_system(time: Res<Time>, mut stars: Query<&mut Transform, With<WordCube>>) {
-    for mut star in &mut stars {
-        star.translation.y -= time.delta_secs() * 0.45;
-        star.rotate_y(time.delta_secs());
-        star.rotate_x(time.delta_secs() * 0.4);
+fn word_cube_urgency_system(
+    mut stars: Query<(&Transform, &mut Urgency), With<WordCube>>,
+    camera: Single<(&Camera, &GlobalTransform), With<RatatuiCamera>>,
+) {
+    let (camera, camera_transform) = camera.into_inner();
+
+    for (transform, mut urgency) in &mut stars {
+        // Nearer cubes leave the view sooner, so the bottom is measured at each cube's own depth.
+        let Some(lowest_visible_y) =
+            get_lowest_visible_y(camera, camera_transform, -transform.translation.z)
+        else {
+            continue;
+        };
+
+        let distance = urgency.start_y - lowest_visible_y;
+        if distance > 0. {
+            urgency.level = ((urgency.start_y - transform.translation.y) / distance).clamp(0., 1.);
+        }
     }
 }
 
 fn word_cube_despawn_system(
     mut commands: Commands,
-    mut stars: Query<(Entity, &mut Transform), With<WordCube>>,
+    stars: Query<(Entity, &Interpolated, &WordCube)>,
     camera: Single<(&Camera, &GlobalTransform), With<RatatuiCamera>>,
+    current_letter: Res<CurrentLetter>,
 ) {
     let (camera, camera_transform) = camera.into_inner();
     let Some(lowest_visible_y) = get_lowest_visible_y(camera, camera_transform, 4.0) else {
         return;
     };
 
-    for (entity, transform) in &mut stars {
-        if transform.translation.y < lowest_visible_y {
+    for (entity, interpolated, word_cube) in &stars {
+        if interpolated.current.translation.y < lowest_visible_y {
             commands.entity(entity).despawn();
+
+            if current_letter.blessings.iter().any(|blessing| {
+                blessing.target_word == word_cube.word && !blessing.collected && !blessing.lost
+            }) {
+                commands.trigger(BlessingMissed {
+                    word: word_cube.word.clone(),
+                });
+            }
         }
     }
 }
 
-fn get_spawn_position(
+pub(super) fn get_spawn_position(
     camera: &Camera,
     camera_transform: &GlobalTransform,
     near_depth: f32,
@@ -131,7 +252,7 @@ fn get_spawn_position(
     Some(Vec3::new(x, y, -z))
 }
 
-fn get_lowest_visible_y(
+pub(super) fn get_lowest_visible_y(
     camera: &Camera,
     camera_transform: &GlobalTransform,
     far_depth: f32,
@@ -0,0 +1,246 @@
+use std::f32::consts::PI;
+
+use bevy::{
+    pbr::{DistanceFog, FogFalloff},
+    prelude::*,
+};
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_common_assets::ron::RonAssetPlugin;
+use bevy_ratatui_camera::RatatuiCamera;
+use serde::Deserialize;
+
+use crate::{
+    letters::CurrentLetter,
+    states::{GameStates, generate_current_letter_system},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins(RonAssetPlugin::<SceneTheme>::new(&["theme.ron"]))
+        .add_systems(
+            OnEnter(GameStates::Printing),
+            swap_scene_theme_system.after(generate_current_letter_system),
+        )
+        .add_systems(Update, theme_prop_motion_system);
+}
+
+#[derive(AssetCollection, Resource)]
+pub struct SceneThemeAssets {
+    #[asset(key = "themes", collection(typed))]
+    pub themes: Vec<Handle<SceneTheme>>,
+}
+
+// Lighting, fog and background props that a flavor can set its letters in.
+#[derive(Debug, Deserialize, Asset, TypePath, Clone)]
+pub struct SceneTheme {
+    // Name that flavors refer to the theme by.
+    pub name: String,
+    pub light: ThemeLight,
+    #[serde(default)]
+    pub fog: Option<ThemeFog>,
+    #[serde(default)]
+    pub props: Vec<ThemeProp>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub struct ThemeLight {
+    pub color: (f32, f32, f32),
+    // Brightness, as a multiple of the default light.
+    pub intensity: f32,
+    // Shines in this direction like sunlight, instead of from a point at the camera.
+    #[serde(default)]
+    pub direction: Option<(f32, f32, f32)>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub struct ThemeFog {
+    pub color: (f32, f32, f32),
+    // Distances from the camera that fog starts at and fully covers the scene at.
+    pub start: f32,
+    pub end: f32,
+}
+
+// Piece of background geometry, placed behind the word cubes.
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub struct ThemeProp {
+    pub shape: PropShape,
+    pub position: (f32, f32, f32),
+    pub color: (f32, f32, f32),
+    // Radians per second turned around the vertical axis.
+    #[serde(default)]
+    pub spin: f32,
+    // Height bobbed up and down by.
+    #[serde(default)]
+    pub bob: f32,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub enum PropShape {
+    Envelope { size: f32 },
+    // Sphere with a tilted ring, so that it can be seen turning.
+    Globe { radius: f32 },
+    Mailbox { size: f32 },
+}
+
+impl PropShape {
+    // Meshes making up the shape, with their offsets from its position.
+    fn parts(&self) -> Vec<(Mesh, Transform)> {
+        match *self {
+            PropShape::Envelope { size } => vec![
+                (
+                    Cuboid::new(size * 1.6, size, size * 0.05).into(),
+                    Transform::IDENTITY,
+                ),
+                // Flap folded down over the front.
+                (
+                    Cuboid::new(size * 1.2, size * 0.6, size * 0.05).into(),
+                    Transform::from_xyz(0., size * 0.15, size * 0.04)
+                        .with_rotation(Quat::from_rotation_z(PI / 4.)),
+                ),
+            ],
+            PropShape::Globe { radius } => vec![
+                (Sphere::new(radius).into(), Transform::IDENTITY),
+                (
+                    Torus::new(radius * 1.2, radius * 1.3).into(),
+                    Transform::from_rotation(Quat::from_rotation_z(0.4)),
+                ),
+            ],
+            PropShape::Mailbox { size } => vec![
+                (
+                    Cylinder::new(size * 0.08, size * 1.5).into(),
+                    Transform::from_xyz(0., -size * 0.75, 0.),
+                ),
+                (
+                    Cuboid::new(size * 0.6, size * 0.5, size).into(),
+                    Transform::from_xyz(0., size * 0.25, 0.),
+                ),
+                // Raised flag on the side.
+                (
+                    Cuboid::new(size * 0.05, size * 0.4, size * 0.15).into(),
+                    Transform::from_xyz(size * 0.33, size * 0.5, -size * 0.3),
+                ),
+            ],
+        }
+    }
+}
+
+// Anything spawned for the current theme, cleared out when the next letter swaps it.
+#[derive(Component, Debug)]
+pub struct ThemeEntity;
+
+#[derive(Component, Debug)]
+struct PropMotion {
+    anchor: Vec3,
+    spin: f32,
+    bob: f32,
+}
+
+fn swap_scene_theme_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    current_letter: Res<CurrentLetter>,
+    theme_assets: Res<SceneThemeAssets>,
+    themes: Res<Assets<SceneTheme>>,
+    theme_entities: Query<Entity, With<ThemeEntity>>,
+    camera: Single<Entity, With<RatatuiCamera>>,
+) {
+    for entity in &theme_entities {
+        commands.entity(entity).despawn();
+    }
+    commands.entity(*camera).remove::<DistanceFog>();
+
+    let theme = current_letter.flavor.theme.as_ref().and_then(|name| {
+        let theme = theme_assets
+            .themes
+            .iter()
+            .filter_map(|handle| themes.get(handle))
+            .find(|theme| theme.name == *name);
+
+        if theme.is_none() {
+            warn!("scene theme {name} does not exist");
+        }
+
+        theme
+    });
+
+    // Letters without a theme keep the plain light at the camera.
+    let Some(theme) = theme else {
+        commands.spawn((PointLight::default(), ThemeEntity));
+        return;
+    };
+
+    let (red, green, blue) = theme.light.color;
+    let light_color = Color::srgb(red, green, blue);
+    match theme.light.direction {
+        Some((x, y, z)) => {
+            commands.spawn((
+                DirectionalLight {
+                    color: light_color,
+                    illuminance: DirectionalLight::default().illuminance * theme.light.intensity,
+                    ..default()
+                },
+                Transform::default().looking_to(Vec3::new(x, y, z), Vec3::Y),
+                ThemeEntity,
+            ));
+        }
+        None => {
+            commands.spawn((
+                PointLight {
+                    color: light_color,
+                    intensity: PointLight::default().intensity * theme.light.intensity,
+                    ..default()
+                },
+                ThemeEntity,
+            ));
+        }
+    }
+
+    if let Some(fog) = theme.fog {
+        let (red, green, blue) = fog.color;
+        commands.entity(*camera).insert(DistanceFog {
+            color: Color::srgb(red, green, blue),
+            falloff: FogFalloff::Linear {
+                start: fog.start,
+                end: fog.end,
+            },
+            ..default()
+        });
+    }
+
+    for prop in &theme.props {
+        let (x, y, z) = prop.position;
+        let (red, green, blue) = prop.color;
+        let material = materials.add(Color::srgb(red, green, blue));
+        let anchor = Vec3::new(x, y, z);
+
+        commands
+            .spawn((
+                Transform::from_translation(anchor),
+                Visibility::default(),
+                PropMotion {
+                    anchor,
+                    spin: prop.spin,
+                    bob: prop.bob,
+                },
+                ThemeEntity,
+            ))
+            .with_children(|parent| {
+                for (mesh, transform) in prop.shape.parts() {
+                    parent.spawn((
+                        Mesh3d(meshes.add(mesh)),
+                        MeshMaterial3d(material.clone()),
+                        transform,
+                    ));
+                }
+            });
+    }
+}
+
+fn theme_prop_motion_system(time: Res<Time>, mut props: Query<(&mut Transform, &PropMotion)>) {
+    for (mut transform, motion) in &mut props {
+        transform.rotate_y(motion.spin * time.delta_secs());
+        // Offset by position, so that props do not all bob in step.
+        let phase = time.elapsed_secs() + motion.anchor.x;
+        transform.translation.y = motion.anchor.y + motion.bob * phase.sin();
+    }
+}
@@ -0,0 +1,239 @@
+use bevy::{
+    asset::RenderAssetUsages,
+    prelude::*,
+    render::mesh::{Indices, PrimitiveTopology},
+};
+
+use crate::{
+    constants::{WORD_MESH_MIN_SCENE_HEIGHT, WORD_MESH_MIN_SCENE_WIDTH, WORD_VOXEL_SIZE},
+    interface::hitboxes::Hitboxes,
+    settings::Settings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<WordMeshesActive>().add_systems(
+        Update,
+        (update_word_meshes_active_system, swap_word_meshes_system).chain(),
+    );
+}
+
+const GLYPH_WIDTH: usize = 3;
+const GLYPH_HEIGHT: usize = 5;
+
+// Whether words are drawn as meshes this frame, rather than as labels over the scene. Small
+// scenes fall back to labels, since they do not have the cells to spell words out in.
+#[derive(Resource, Debug, Default)]
+pub struct WordMeshesActive(pub bool);
+
+// Meshes a word cube can be drawn with, swapped between as word meshes turn on and off.
+#[derive(Component, Debug, Clone)]
+pub struct WordMeshes {
+    pub cube: Handle<Mesh>,
+    pub text: Handle<Mesh>,
+}
+
+// Rows of a glyph from the top, with the leftmost column in the highest bit.
+fn glyph(character: char) -> Option<[u8; GLYPH_HEIGHT]> {
+    let rows = match character.to_ascii_uppercase() {
+        'A' => [0b010, 0b101, 0b111, 0b101, 0b101],
+        'B' => [0b110, 0b101, 0b110, 0b101, 0b110],
+        'C' => [0b011, 0b100, 0b100, 0b100, 0b011],
+        'D' => [0b110, 0b101, 0b101, 0b101, 0b110],
+        'E' => [0b111, 0b100, 0b110, 0b100, 0b111],
+        'F' => [0b111, 0b100, 0b110, 0b100, 0b100],
+        'G' => [0b011, 0b100, 0b101, 0b101, 0b011],
+        'H' => [0b101, 0b101, 0b111, 0b101, 0b101],
+        'I' => [0b111, 0b010, 0b010, 0b010, 0b111],
+        'J' => [0b001, 0b001, 0b001, 0b101, 0b010],
+        'K' => [0b101, 0b101, 0b110, 0b101, 0b101],
+        'L' => [0b100, 0b100, 0b100, 0b100, 0b111],
+        'M' => [0b101, 0b111, 0b101, 0b101, 0b101],
+        'N' => [0b110, 0b101, 0b101, 0b101, 0b101],
+        'O' => [0b010, 0b101, 0b101, 0b101, 0b010],
+        'P' => [0b110, 0b101, 0b110, 0b100, 0b100],
+        'Q' => [0b010, 0b101, 0b101, 0b110, 0b011],
+        'R' => [0b110, 0b101, 0b110, 0b101, 0b101],
+        'S' => [0b011, 0b100, 0b010, 0b001, 0b110],
+        'T' => [0b111, 0b010, 0b010, 0b010, 0b010],
+        'U' => [0b101, 0b101, 0b101, 0b101, 0b111],
+        'V' => [0b101, 0b101, 0b101, 0b101, 0b010],
+        'W' => [0b101, 0b101, 0b101, 0b111, 0b101],
+        'X' => [0b101, 0b101, 0b010, 0b101, 0b101],
+        'Y' => [0b101, 0b101, 0b010, 0b010, 0b010],
+        'Z' => [0b111, 0b001, 0b010, 0b100, 0b111],
+        '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+        '\'' => [0b010, 0b010, 0b000, 0b000, 0b000],
+        _ => return None,
+    };
+
+    Some(rows)
+}
+
+// Mesh spelling out a word in blocks, centered on the origin and facing +Z. Characters without a
+// glyph are left as gaps.
+pub fn word_mesh(word: &str) -> Mesh {
+    let characters: Vec<char> = word.chars().collect();
+    let columns = (characters.len() * (GLYPH_WIDTH + 1)).saturating_sub(1);
+    let center = Vec2::new(columns as f32 - 1., GLYPH_HEIGHT as f32 - 1.) / 2.;
+
+    let mut positions = vec![];
+    let mut normals = vec![];
+    let mut indices = vec![];
+
+    for (index, character) in characters.into_iter().enumerate() {
+        let Some(rows) = glyph(character) else {
+            continue;
+        };
+
+        for (row, bits) in rows.into_iter().enumerate() {
+            for column in 0..GLYPH_WIDTH {
+                if (bits >> (GLYPH_WIDTH - 1 - column)) & 1 == 0 {
+                    continue;
+                }
+
+                let cell = Vec2::new(
+                    (index * (GLYPH_WIDTH + 1) + column) as f32,
+                    (GLYPH_HEIGHT - 1 - row) as f32,
+                ) - center;
+                push_voxel(
+                    cell.extend(0.) * WORD_VOXEL_SIZE,
+                    &mut positions,
+                    &mut normals,
+                    &mut indices,
+                );
+            }
+        }
+    }
+
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+    .with_inserted_indices(Indices::U32(indices))
+}
+
+// Adds the six faces of a block, each wound counter-clockwise when seen from outside.
+fn push_voxel(
+    center: Vec3,
+    positions: &mut Vec<[f32; 3]>,
+    normals: &mut Vec<[f32; 3]>,
+    indices: &mut Vec<u32>,
+) {
+    let half = WORD_VOXEL_SIZE / 2.;
+    // Each face's normal, and two axes across it whose cross product is the normal.
+    let faces = [
+        (Vec3::X, Vec3::Y, Vec3::Z),
+        (Vec3::NEG_X, Vec3::Z, Vec3::Y),
+        (Vec3::Y, Vec3::Z, Vec3::X),
+        (Vec3::NEG_Y, Vec3::X, Vec3::Z),
+        (Vec3::Z, Vec3::X, Vec3::Y),
+        (Vec3::NEG_Z, Vec3::Y, Vec3::X),
+    ];
+
+    for (normal, u, v) in faces {
+        let base = positions.len() as u32;
+        for (u_sign, v_sign) in [(-1., -1.), (1., -1.), (1., 1.), (-1., 1.)] {
+            let corner = center + (normal + u * u_sign + v * v_sign) * half;
+            positions.push(corner.to_array());
+            normals.push(normal.to_array());
+        }
+        indices.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+    }
+}
+
+fn update_word_meshes_active_system(
+    settings: Res<Settings>,
+    hitboxes: Res<Hitboxes>,
+    mut active: ResMut<WordMeshesActive>,
+) {
+    let fits = hitboxes.scene_area.width >= WORD_MESH_MIN_SCENE_WIDTH
+        && hitboxes.scene_area.height >= WORD_MESH_MIN_SCENE_HEIGHT;
+    let word_meshes_active = settings.word_meshes && fits;
+
+    if active.0 != word_meshes_active {
+        active.0 = word_meshes_active;
+    }
+}
+
+fn swap_word_meshes_system(
+    active: Res<WordMeshesActive>,
+    mut word_cubes: Query<(&WordMeshes, &mut Mesh3d)>,
+) {
+    for (word_meshes, mut mesh) in &mut word_cubes {
+        let handle = if active.0 {
+            &word_meshes.text
+        } else {
+            &word_meshes.cube
+        };
+
+        if mesh.0 != *handle {
+            mesh.0 = handle.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn positions(mesh: &Mesh) -> &[[f32; 3]] {
+        mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+            .and_then(|positions| positions.as_float3())
+            .unwrap()
+    }
+
+    // Number of lit cells in a glyph, which each become a block.
+    fn blocks(character: char) -> usize {
+        glyph(character)
+            .unwrap()
+            .iter()
+            .map(|row| row.count_ones() as usize)
+            .sum()
+    }
+
+    #[test]
+    fn every_letter_has_a_glyph() {
+        for character in ('a'..='z').chain('A'..='Z') {
+            assert!(glyph(character).is_some(), "{character} has no glyph");
+        }
+        assert_eq!(glyph('q'), glyph('Q'));
+        assert!(glyph('?').is_none());
+    }
+
+    #[test]
+    fn builds_one_block_per_lit_cell() {
+        let mesh = word_mesh("hi");
+        let expected = blocks('h') + blocks('i');
+
+        // Each block has six faces of four corners and two triangles.
+        assert_eq!(positions(&mesh).len(), expected * 24);
+        assert_eq!(mesh.indices().unwrap().len(), expected * 36);
+    }
+
+    #[test]
+    fn characters_without_glyphs_leave_gaps() {
+        let gap = word_mesh("i?i");
+        let together = word_mesh("ii");
+
+        assert_eq!(positions(&gap).len(), positions(&together).len());
+        let width = |mesh: &Mesh| {
+            let xs = positions(mesh).iter().map(|[x, _, _]| *x);
+            xs.clone().fold(f32::MIN, f32::max) - xs.fold(f32::MAX, f32::min)
+        };
+        assert!(width(&gap) > width(&together));
+    }
+
+    #[test]
+    fn words_are_centered_on_the_origin() {
+        let mesh = word_mesh("mail");
+        let positions = positions(&mesh);
+
+        for axis in 0..3 {
+            let min = positions.iter().map(|p| p[axis]).fold(f32::MAX, f32::min);
+            let max = positions.iter().map(|p| p[axis]).fold(f32::MIN, f32::max);
+            assert!((min + max).abs() < 1e-4, "axis {axis} spans {min} to {max}");
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    export::ExportFormat,
+    scene::camera::RenderStyle,
+    storage::{config_dir, read_ron, write_ron},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(Settings::load());
+}
+
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Settings {
+    // Names of letter packs (from their manifests) that should not be loaded.
+    pub disabled_packs: Vec<String>,
+    // Format that cleared letters are exported in.
+    pub export_format: ExportFormat,
+    // Clicking a word in the scene submits it right away, instead of filling the prompt.
+    pub click_to_submit: bool,
+    pub difficulty: Difficulty,
+    // Typing assists turned on at each difficulty.
+    pub assists: Assists,
+    // How the 3D scene is drawn, which takes effect on the next launch.
+    pub render_style: RenderStyle,
+    // Tones down flashing, blinking and shaking effects.
+    pub reduced_motion: bool,
+    // Spells words out as blocks in the scene, instead of labelling cubes, when there is room.
+    pub word_meshes: bool,
+    // How often the game simulates and draws, which take effect on the next launch.
+    pub timing: Timing,
+}
+
+impl Settings {
+    pub fn path() -> Option<PathBuf> {
+        config_dir().map(|dir| dir.join("settings.ron"))
+    }
+
+    // Reads settings from the config directory, writing out the defaults if none exist yet.
+    pub fn load() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+
+        if let Some(mut settings) = read_ron::<Self>(&path) {
+            settings.timing = settings.timing.validated();
+            return settings;
+        }
+
+        let settings = Self::default();
+        if !path.exists() {
+            settings.save();
+        }
+
+        settings
+    }
+
+    pub fn assist(&self) -> Assist {
+        match self.difficulty {
+            Difficulty::Easy => self.assists.easy,
+            Difficulty::Normal => self.assists.normal,
+            Difficulty::Hard => self.assists.hard,
+        }
+    }
+
+    pub fn save(&self) {
+        if let Some(path) = Self::path() {
+            write_ron(&path, self);
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn next(self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Easy,
+        }
+    }
+}
+
+impl std::fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Difficulty::Easy => write!(f, "EASY"),
+            Difficulty::Normal => write!(f, "NORMAL"),
+            Difficulty::Hard => write!(f, "HARD"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct Assists {
+    pub easy: Assist,
+    pub normal: Assist,
+    pub hard: Assist,
+}
+
+impl Default for Assists {
+    fn default() -> Self {
+        Self {
+            easy: Assist {
+                ghost_text: true,
+                fuzzy_matching: true,
+                ..default()
+            },
+            normal: Assist::default(),
+            hard: Assist {
+                respawn_missed_blessings: false,
+                ..default()
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[serde(default)]
+pub struct Assist {
+    // Show the rest of a visible word after what has been typed so far.
+    pub ghost_text: bool,
+    // Accept guesses one typo away from exactly one visible word.
+    pub fuzzy_matching: bool,
+    // Score, money and income gained from a fuzzy match, as a fraction of the full amounts.
+    pub fuzzy_score_multiplier: f32,
+    // Blessings that fall off screen come around again, instead of being lost for the letter.
+    pub respawn_missed_blessings: bool,
+}
+
+impl Default for Assist {
+    fn default() -> Self {
+        Self {
+            ghost_text: false,
+            fuzzy_matching: false,
+            fuzzy_score_multiplier: 0.5,
+            respawn_missed_blessings: true,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[serde(default)]
+pub struct Timing {
+    // Steps per second of gameplay, which is the same in every build.
+    pub simulation_rate: f64,
+    // Frames per second drawn, at most. Windowed builds are also limited by the display.
+    pub render_rate: f64,
+}
+
+impl Default for Timing {
+    fn default() -> Self {
+        Self {
+            simulation_rate: 60.,
+            render_rate: 90.,
+        }
+    }
+}
+
+impl Timing {
+    // Rates that are not positive and finite fall back to their defaults, since a step or frame
+    // time cannot be made from them.
+    fn validated(self) -> Self {
+        let defaults = Self::default();
+        let valid = |rate: f64| rate.is_finite() && rate > 0.;
+
+        let simulation_rate = if valid(self.simulation_rate) {
+            self.simulation_rate
+        } else {
+            warn!(
+                "simulation rate {} is not a positive number, using {}",
+                self.simulation_rate, defaults.simulation_rate
+            );
+            defaults.simulation_rate
+        };
+
+        let render_rate = if valid(self.render_rate) {
+            self.render_rate
+        } else {
+            warn!(
+                "render rate {} is not a positive number, using {}",
+                self.render_rate, defaults.render_rate
+            );
+            defaults.render_rate
+        };
+
+        Self {
+            simulation_rate,
+            render_rate,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+
+use crate::settings::Settings;
+
+pub(super) fn plugin(app: &mut App) {
+    let simulation_rate = app.world().resource::<Settings>().timing.simulation_rate;
+
+    app.insert_resource(Time::<Fixed>::from_hz(simulation_rate))
+        .add_systems(
+            RunFixedMainLoop,
+            interpolate_transforms_system.in_set(RunFixedMainLoopSystem::AfterFixedMainLoop),
+        );
+}
+
+// Transforms as of the last two simulation steps. Gameplay moves entities in `FixedUpdate` by
+// stepping this, and their `Transform` is blended between the two for drawing.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Interpolated {
+    previous: Transform,
+    pub current: Transform,
+}
+
+impl Interpolated {
+    pub fn new(transform: Transform) -> Self {
+        Self {
+            previous: transform,
+            current: transform,
+        }
+    }
+
+    // Moves to where the entity is after this step, keeping where it was to blend from.
+    pub fn step(&mut self, transform: Transform) {
+        self.previous = self.current;
+        self.current = transform;
+    }
+}
+
+fn interpolate_transforms_system(
+    time: Res<Time<Fixed>>,
+    mut interpolated: Query<(&mut Transform, &Interpolated)>,
+) {
+    let alpha = time.overstep_fraction();
+
+    for (mut transform, interpolated) in &mut interpolated {
+        let Interpolated { previous, current } = interpolated;
+        transform.translation = previous.translation.lerp(current.translation, alpha);
+        transform.rotation = previous.rotation.slerp(current.rotation, alpha);
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
     constants::{BLESSING_RANGE, CURSE_RANGE, DECOY_RANGE},
     interface::widgets::{letter::LetterWidgetState, prompt::Prompt},
     letters::{CurrentLetter, Flavor, LetterAssets, LetterBag, Name, TestimonialStub, WordBag},
+    metrics::ResetTally,
     rng::RngResource,
     scene::spawning::WordCube,
     sound::SoundEffect,
@@ -32,6 +33,8 @@ pub(super) fn plugin(app: &mut App) {
 pub enum GameStates {
     #[default]
     Loading,
+    // Letter packs are loaded separately, so that a broken pack cannot hold up the game.
+    LoadingPacks,
     Info,
     Printing,
     Playing,
@@ -127,16 +130,17 @@ pub fn letter_failed_observer(_trigger: Trigger<LetterFailed>, mut commands: Com
 pub fn resetting_timer_system(
     mut commands: Commands,
     mut current_letter_state: NonSendMut<LetterWidgetState>,
+    tally: Res<ResetTally>,
 ) {
     if let Some(timer) = current_letter_state.effect.timer_mut() {
-        if timer.done() {
+        if timer.done() && tally.finished() {
             commands.set_state(GameStates::Printing);
         }
     }
 }
 
 fn clear_prompt_system(mut prompt: ResMut<Prompt>) {
-    prompt.text = "".into();
+    prompt.clear();
 }
 
 fn clear_word_cubes_system(mut commands: Commands, word_cubes: Query<Entity, With<WordCube>>) {
@@ -0,0 +1,81 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bevy::{asset::ron, prelude::*};
+use serde::{Serialize, de::DeserializeOwned};
+
+// Directory holding user configuration, such as settings and letter packs.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn config_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(base.join("chainmailer"))
+}
+
+// Web builds have no filesystem to store configuration in.
+#[cfg(target_arch = "wasm32")]
+pub fn config_dir() -> Option<PathBuf> {
+    None
+}
+
+// Directory holding files the game writes, such as exported letters.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn data_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+        .or_else(|| {
+            std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local").join("share"))
+        })?;
+
+    Some(base.join("chainmailer"))
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn data_dir() -> Option<PathBuf> {
+    None
+}
+
+// Current time for naming saved files, in seconds with milliseconds after a dash, so that files
+// saved within the same second do not overwrite each other.
+pub fn timestamp_file_stem() -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+
+    format!("{}-{:03}", now.as_secs(), now.subsec_millis())
+}
+
+pub fn read_ron<T: DeserializeOwned>(path: &Path) -> Option<T> {
+    let contents = std::fs::read_to_string(path).ok()?;
+
+    ron::de::from_str(&contents)
+        .inspect_err(|error| warn!("could not parse {}: {error}", path.display()))
+        .ok()
+}
+
+pub fn write_ron<T: Serialize>(path: &Path, value: &T) {
+    let contents = match ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()) {
+        Ok(contents) => contents,
+        Err(error) => {
+            warn!("could not serialize {}: {error}", path.display());
+            return;
+        }
+    };
+
+    if let Some(parent) = path.parent() {
+        if let Err(error) = std::fs::create_dir_all(parent) {
+            warn!("could not create {}: {error}", parent.display());
+            return;
+        }
+    }
+
+    if let Err(error) = std::fs::write(path, contents) {
+        warn!("could not write {}: {error}", path.display());
+    }
+}
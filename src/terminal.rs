@@ -7,17 +7,24 @@ use bevy::log::{LogPlugin, tracing_subscriber};
 use bevy::prelude::*;
 use bevy::winit::WinitPlugin;
 
-pub(super) fn plugin(app: &mut App) {
+use crate::settings::Settings;
+
+// Must be added before any plugin that logs while it is being built.
+pub(super) fn logging_plugin(_app: &mut App) {
     tracing_subscriber::registry()
         .with(tui_logger::TuiTracingSubscriberLayer)
         .init();
     tui_logger::init_logger(tui_logger::LevelFilter::Info).unwrap();
+}
+
+pub(super) fn plugin(app: &mut App) {
+    let render_rate = app.world().resource::<Settings>().timing.render_rate;
 
     app.add_plugins((
         DefaultPlugins
             .set(ImagePlugin::default_nearest())
             .disable::<WinitPlugin>()
             .disable::<LogPlugin>(),
-        ScheduleRunnerPlugin::run_loop(Duration::from_secs_f32(1. / 90.)),
+        ScheduleRunnerPlugin::run_loop(Duration::from_secs_f64(1. / render_rate)),
     ));
 }
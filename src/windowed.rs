@@ -1,7 +1,28 @@
-use bevy::{asset::AssetMetaCheck, prelude::*};
+use std::time::Duration;
+
+use bevy::{
+    asset::AssetMetaCheck,
+    log::LogPlugin,
+    prelude::*,
+    winit::{UpdateMode, WinitSettings},
+};
+
+use crate::settings::Settings;
+
+// Must be added before any plugin that logs while it is being built.
+pub(super) fn logging_plugin(app: &mut App) {
+    app.add_plugins(LogPlugin::default());
+}
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_plugins(
+    let render_rate = app.world().resource::<Settings>().timing.render_rate;
+    let update_mode = UpdateMode::reactive(Duration::from_secs_f64(1. / render_rate));
+
+    app.insert_resource(WinitSettings {
+        focused_mode: update_mode,
+        unfocused_mode: update_mode,
+    })
+    .add_plugins(
         DefaultPlugins
             .set(AssetPlugin {
                 // Wasm builds will check for meta files (that don't exist) if this isn't set.
@@ -19,6 +40,7 @@ pub(super) fn plugin(app: &mut App) {
                 }
                 .into(),
                 ..default()
-            }),
+            })
+            .disable::<LogPlugin>(),
     );
 }
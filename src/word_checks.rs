@@ -1,89 +1,307 @@
+use std::time::Duration;
+
 use bevy::prelude::*;
 
 use crate::{
     constants::{MAC_GREEN_COLOR, MAC_PURPLE_COLOR, MAC_RED_COLOR, MAC_YELLOW_COLOR},
-    interface::widgets::{confetti::ConfettiSpawn, prompt::Prompt},
+    interface::{
+        hitboxes::Hitboxes,
+        widgets::{
+            confetti::{ConfettiSpawn, EmitterPreset, ParticleOrigin},
+            prompt::Prompt,
+        },
+    },
     letters::{CurrentLetter, Effect, WordBag},
     rng::RngResource,
-    scene::spawning::WordCube,
+    scene::{
+        objects::{PowerUpActivated, SceneObject},
+        spawning::{BlessingMissed, SpawnedAt, WordCube},
+    },
+    settings::Settings,
     sound::SoundEffect,
-    states::{LetterCleared, Statistics},
+    states::{LetterCleared, LetterFailed, Statistics},
 };
 
 pub(super) fn plugin(app: &mut App) {
     app.add_observer(submitted_word_observer)
-        .add_observer(activate_effect_observer);
+        .add_observer(activate_effect_observer)
+        .add_observer(blessing_missed_observer);
 }
 
 #[derive(Event)]
 pub struct SubmittedWord;
 
 #[derive(Event)]
-pub struct ActivateEffect(Effect);
+pub struct ActivateEffect(pub Effect);
+
+// A curse's word was guessed, with the effect it had.
+#[derive(Event, Debug, Clone)]
+pub struct CurseGuessed(pub Effect);
+
+// Result of a submitted guess, triggered once per submission.
+#[derive(Event, Debug, Clone)]
+pub struct GuessOutcome {
+    pub word: String,
+    pub kind: GuessKind,
+    // Whether the guess only matched thanks to fuzzy matching.
+    pub fuzzy: bool,
+    // Time between the guessed word cube appearing and the guess, if it was on screen.
+    pub reaction_time: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuessKind {
+    Blessing,
+    Curse,
+    Decoy,
+    // No word cube had the guessed word.
+    Miss,
+}
 
 fn submitted_word_observer(
     _trigger: Trigger<SubmittedWord>,
     mut commands: Commands,
     mut prompt: ResMut<Prompt>,
     mut current_letter: ResMut<CurrentLetter>,
-    word_cubes: Query<(Entity, &WordCube, &Transform)>,
+    word_cubes: Query<(Entity, &WordCube, &Transform, &SpawnedAt)>,
+    scene_objects: Query<(Entity, &SceneObject)>,
     mut word_bag: ResMut<WordBag>,
     mut rng: Local<RngResource>,
+    hitboxes: Res<Hitboxes>,
+    settings: Res<Settings>,
+    time: Res<Time>,
 ) {
-    for (entity, word_cube, transform) in &word_cubes {
-        let mut decoy = false;
+    // Power-up command words are not guesses, and do not count towards any stats.
+    if let Some((entity, _)) = scene_objects
+        .iter()
+        .find(|(_, scene_object)| scene_object.command() == Some(prompt.text.as_str()))
+    {
+        commands.trigger(PowerUpActivated(entity));
+        prompt.submit();
+        return;
+    }
+
+    let assist = settings.assist();
+    let exact = word_cubes
+        .iter()
+        .any(|(_, word_cube, _, _)| word_cube.word == prompt.text);
+
+    // A near miss of exactly one visible word counts as that word, for reduced rewards.
+    let fuzzy_match = if assist.fuzzy_matching && !exact && !prompt.text.is_empty() {
+        let mut candidates: Vec<_> = hitboxes
+            .word_labels
+            .iter()
+            .map(|(_, word)| word)
+            // Power-up labels are commands, which are only ever typed exactly.
+            .filter(|word| {
+                !scene_objects
+                    .iter()
+                    .any(|(_, scene_object)| scene_object.command() == Some(word.as_str()))
+            })
+            .filter(|word| within_one_edit(word, &prompt.text))
+            .collect();
+        candidates.sort();
+        candidates.dedup();
+
+        match candidates[..] {
+            [word] => Some(word.clone()),
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    let guess = fuzzy_match.clone().unwrap_or_else(|| prompt.text.clone());
+    // Fuzzy matches scale down every gain, but never soften a loss.
+    let reward = |effect: &Effect| {
+        let scale = |amount: i32| {
+            if fuzzy_match.is_some() && amount > 0 {
+                (amount as f32 * assist.fuzzy_score_multiplier).round() as i32
+            } else {
+                amount
+            }
+        };
+
+        match *effect {
+            Effect::Score(score) => Effect::Score(scale(score)),
+            Effect::Money(money) => Effect::Money(scale(money)),
+            Effect::Income(income) => Effect::Income(scale(income)),
+            Effect::Noop => Effect::Noop,
+        }
+    };
+
+    let kind = if current_letter
+        .blessings
+        .iter()
+        .any(|blessing| blessing.target_word == guess && !blessing.collected && !blessing.lost)
+    {
+        GuessKind::Blessing
+    } else if current_letter
+        .curses
+        .iter()
+        .any(|curse| curse.target_word == guess && !curse.collected)
+    {
+        GuessKind::Curse
+    } else if word_cubes
+        .iter()
+        .any(|(_, word_cube, _, _)| word_cube.word == guess)
+    {
+        GuessKind::Decoy
+    } else {
+        GuessKind::Miss
+    };
 
+    let reaction_time = word_cubes
+        .iter()
+        .find(|(_, word_cube, _, _)| word_cube.word == guess)
+        .map(|(_, _, _, spawned_at)| time.elapsed().saturating_sub(spawned_at.0));
+
+    commands.trigger(GuessOutcome {
+        word: guess.clone(),
+        kind,
+        fuzzy: fuzzy_match.is_some(),
+        reaction_time,
+    });
+
+    for (entity, word_cube, transform, _) in &word_cubes {
         if let Some(index) = word_bag
             .full_collection
             .iter()
-            .position(|word_cube| word_cube.word == prompt.text)
+            .position(|word_cube| word_cube.word == guess)
         {
             word_bag.full_collection.remove(index);
         };
         word_bag.reset(&mut rng.0);
 
-        if word_cube.word == prompt.text {
-            decoy = true;
-
+        if word_cube.word == guess {
             commands.entity(entity).despawn();
             commands.trigger(ConfettiSpawn {
-                position: transform.translation,
+                preset: EmitterPreset::Burst,
+                origin: ParticleOrigin::World(transform.translation),
                 color: color_for_character(&word_cube.despawn_character),
                 character: word_cube.despawn_character,
             });
         }
+    }
 
-        for blessing in &mut current_letter.blessings {
-            if blessing.target_word == prompt.text {
-                blessing.collected = true;
-                decoy = false;
-                commands.trigger(SoundEffect::GuessBless);
-                commands.trigger(ActivateEffect(blessing.effect.clone()));
+    // Effects are activated once per guess, however many cubes had the guessed word.
+    for blessing in &mut current_letter.blessings {
+        if blessing.target_word == guess && !blessing.collected && !blessing.lost {
+            blessing.collected = true;
+            commands.trigger(SoundEffect::GuessBless);
+            commands.trigger(ActivateEffect(reward(&blessing.effect)));
+            if let Some(cell) = hitboxes.testimonial_cell(&guess) {
+                commands.trigger(ConfettiSpawn {
+                    preset: EmitterPreset::Sparkle,
+                    origin: ParticleOrigin::Letter(cell),
+                    character: '+',
+                    color: MAC_GREEN_COLOR,
+                });
             }
         }
+    }
 
-        for curse in &mut current_letter.curses {
-            if curse.target_word == prompt.text {
-                curse.collected = true;
-                decoy = false;
-                commands.trigger(SoundEffect::GuessCurse);
-                commands.trigger(ActivateEffect(curse.effect.clone()));
+    for curse in &mut current_letter.curses {
+        if curse.target_word == guess && !curse.collected {
+            curse.collected = true;
+            commands.trigger(SoundEffect::GuessCurse);
+            commands.trigger(CurseGuessed(curse.effect.clone()));
+            commands.trigger(ActivateEffect(reward(&curse.effect)));
+            if let Some(cell) = hitboxes.testimonial_cell(&guess) {
+                commands.trigger(ConfettiSpawn {
+                    preset: EmitterPreset::Drift,
+                    origin: ParticleOrigin::Letter(cell),
+                    character: '~',
+                    color: MAC_RED_COLOR,
+                });
             }
         }
+    }
 
-        if decoy {
-            commands.trigger(SoundEffect::GuessDecoy);
-        }
+    if kind == GuessKind::Decoy {
+        commands.trigger(SoundEffect::GuessDecoy);
     }
 
-    prompt.text = "".into();
+    prompt.submit();
 
-    if current_letter
-        .blessings
+    // Only a guess that collected the last blessing clears the letter, since a letter resolved by
+    // missed blessings has already ended.
+    if kind == GuessKind::Blessing && current_letter.blessings_resolved() {
+        commands.trigger(LetterCleared);
+    }
+}
+
+// On difficulties where missed blessings do not respawn, their words are taken out of the bag.
+fn blessing_missed_observer(
+    trigger: Trigger<BlessingMissed>,
+    mut commands: Commands,
+    mut current_letter: ResMut<CurrentLetter>,
+    mut word_bag: ResMut<WordBag>,
+    mut rng: Local<RngResource>,
+    settings: Res<Settings>,
+) {
+    if settings.assist().respawn_missed_blessings {
+        return;
+    }
+
+    let word = &trigger.event().word;
+
+    let mut newly_lost = false;
+    for blessing in &mut current_letter.blessings {
+        if blessing.target_word == *word && !blessing.collected && !blessing.lost {
+            blessing.lost = true;
+            newly_lost = true;
+        }
+    }
+
+    if let Some(index) = word_bag
+        .full_collection
         .iter()
-        .all(|blessing| blessing.collected)
+        .position(|word_cube| word_cube.word == *word)
     {
-        commands.trigger(LetterCleared);
+        word_bag.full_collection.remove(index);
+        word_bag.reset(&mut rng.0);
+    }
+
+    // Once nothing is left to collect, the letter is over, and only counts if something was saved.
+    // Only the blessing that resolved the letter ends it, so a second cube of the same word falling
+    // off screen does not end it again.
+    if newly_lost && current_letter.blessings_resolved() {
+        if current_letter
+            .blessings
+            .iter()
+            .any(|blessing| blessing.collected)
+        {
+            commands.trigger(LetterCleared);
+        } else {
+            commands.trigger(LetterFailed);
+        }
+    }
+}
+
+// Whether one insertion, deletion or substitution turns one word into the other.
+fn within_one_edit(a: &str, b: &str) -> bool {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (shorter, longer) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+
+    if longer.len() - shorter.len() > 1 {
+        return false;
+    }
+
+    let prefix = shorter
+        .iter()
+        .zip(&longer)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    if shorter.len() == longer.len() {
+        shorter[prefix..]
+            .iter()
+            .skip(1)
+            .eq(longer[prefix..].iter().skip(1))
+    } else {
+        shorter[prefix..] == longer[prefix + 1..]
     }
 }
 
@@ -108,3 +326,33 @@ fn activate_effect_observer(trigger: Trigger<ActivateEffect>, mut stats: ResMut<
         Effect::Noop => {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_edit_matches_typos() {
+        assert!(within_one_edit("letter", "letter"));
+        assert!(within_one_edit("letter", "lettr"));
+        assert!(within_one_edit("lettr", "letter"));
+        assert!(within_one_edit("letter", "lxtter"));
+        assert!(within_one_edit("letter", "letters"));
+        assert!(within_one_edit("", "a"));
+    }
+
+    #[test]
+    fn more_than_one_edit_does_not_match() {
+        assert!(!within_one_edit("letter", "lettrs"));
+        assert!(!within_one_edit("letter", "ltetre"));
+        assert!(!within_one_edit("letter", "let"));
+        assert!(!within_one_edit("chain", "chian"));
+    }
+
+    #[test]
+    fn one_edit_counts_characters_rather_than_bytes() {
+        assert!(within_one_edit("café", "cafe"));
+        assert!(within_one_edit("naïve", "nave"));
+        assert!(!within_one_edit("naïve", "nav"));
+    }
+}